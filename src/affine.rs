@@ -0,0 +1,152 @@
+use std::iter::FromIterator;
+
+use crate::{
+    implementation::Storage, Central, Index, Order, Point, Scalar, Spatial, SupportedIndex,
+    SupportedOrder,
+};
+
+/// The affine moment invariants I1 to I4 of Flusser and Suk, which are invariant under translation,
+/// rotation, scaling, shearing and reflection.
+///
+/// For degenerate shapes with an area of (almost) 0, all invariants are 0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Affine<T: Scalar>([T; 4]);
+
+impl<T: Scalar> Affine<T> {
+    /// The invariants in the order I1, I2, I3 and I4.
+    #[inline(always)]
+    pub fn invariants(&self) -> [T; 4] {
+        self.0
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> From<&'a Central<T, ORDER>> for Affine<T>
+where
+    Order<ORDER>: SupportedOrder<T>,
+    Index<3, 0>: SupportedIndex<ORDER>,
+{
+    fn from(moments: &'a Central<T, ORDER>) -> Self {
+        let mu = &moments.0;
+        let m00 = mu.at(0, 0);
+        // Check whether there would be divisions (almost) by 0
+        if m00.abs() <= T::EPSILON {
+            return Affine([T::ZERO; 4]);
+        }
+
+        let (m20, m11, m02) = (mu.at(2, 0), mu.at(1, 1), mu.at(0, 2));
+        let (m30, m21, m12, m03) = (mu.at(3, 0), mu.at(2, 1), mu.at(1, 2), mu.at(0, 3));
+        let c = |value: usize| T::from_usize(value);
+
+        let i1 = m20 * m02 - m11 * m11;
+        let i2 = m30 * m30 * m03 * m03 - c(6) * m30 * m21 * m12 * m03
+            + c(4) * m30 * m12.powi(3)
+            + c(4) * m21.powi(3) * m03
+            - c(3) * m21 * m21 * m12 * m12;
+        let i3 = m20 * (m21 * m03 - m12 * m12) - m11 * (m30 * m03 - m21 * m12)
+            + m02 * (m30 * m12 - m21 * m21);
+        let i4 = m20.powi(3) * m03 * m03
+            - c(6) * m20 * m20 * m11 * m12 * m03
+            - c(6) * m20 * m20 * m02 * m21 * m03
+            + c(9) * m20 * m20 * m02 * m12 * m12
+            + c(12) * m20 * m11 * m11 * m21 * m03
+            + c(6) * m20 * m11 * m02 * m30 * m03
+            - c(18) * m20 * m11 * m02 * m21 * m12
+            - c(8) * m11.powi(3) * m30 * m03
+            - c(6) * m20 * m02 * m02 * m30 * m12
+            + c(9) * m20 * m02 * m02 * m21 * m21
+            + c(12) * m11 * m11 * m02 * m30 * m12
+            - c(6) * m11 * m02 * m02 * m30 * m21
+            + m02.powi(3) * m30 * m30;
+
+        Affine([
+            i1 / m00.powi(4),
+            i2 / m00.powi(10),
+            i3 / m00.powi(7),
+            i4 / m00.powi(11),
+        ])
+    }
+}
+
+impl<T: Scalar, P: Point<T>> FromIterator<P> for Affine<T> {
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        let moments: Spatial<T, 3> = iter.into_iter().collect();
+        Self::from(&Central::from(&moments))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::{assert_abs_diff_eq, assert_relative_eq};
+
+    use crate::{Affine, Central, Spatial};
+
+    const POINTS: [(f64, f64); 7] = [
+        (1.0, 2.0),
+        (7.0, 1.0),
+        (9.0, 6.0),
+        (6.0, 5.0),
+        (4.0, 9.0),
+        (3.0, 4.0),
+        (2.0, 5.0),
+    ];
+
+    /// A simple linear congruential generator for reproducible pseudo-random numbers in [-1, 1).
+    struct Random(u64);
+
+    impl Random {
+        fn next(&mut self) -> f64 {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (self.0 >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+        }
+    }
+
+    #[test]
+    fn test_invariance() {
+        let expected: Affine<f64> = POINTS.iter().collect();
+        let mut random = Random(42);
+
+        for _ in 0..100 {
+            let (a, b, c, d) = (
+                2.0 * random.next(),
+                2.0 * random.next(),
+                2.0 * random.next(),
+                2.0 * random.next(),
+            );
+            let (tx, ty) = (5.0 * random.next(), 5.0 * random.next());
+            // Skip (almost) singular transformations
+            if (a * d - b * c).abs() < 0.1 {
+                continue;
+            }
+
+            let actual: Affine<f64> = POINTS
+                .iter()
+                .map(|(x, y)| (a * x + b * y + tx, c * x + d * y + ty))
+                .collect();
+            for (expected, actual) in expected.invariants().iter().zip(actual.invariants()) {
+                assert_relative_eq!(*expected, actual, max_relative = 10e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_first_invariant() {
+        let moments = Central::from(&POINTS.iter().collect::<Spatial<f64, 3>>()).to_vec();
+        let (m00, m20, m11, m02) = (moments[0], moments[2], moments[5], moments[7]);
+        let invariants: Affine<f64> = POINTS.iter().collect();
+        assert_abs_diff_eq!(
+            invariants.invariants()[0],
+            (m20 * m02 - m11 * m11) / m00.powi(4),
+            epsilon = 10e-15
+        );
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let line = [(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)];
+        let invariants: Affine<f64> = line.iter().collect();
+        assert_eq!(invariants.invariants(), [0.0; 4]);
+    }
+}
@@ -0,0 +1,387 @@
+use std::convert::TryFrom;
+
+use crate::{
+    implementation::{binomial, calculate_complex_moment, complex_mul, complex_powi, Storage},
+    Central, DynCentral, DynNormalizedCentral, Index, InsufficientOrder, NormalizedCentral, Order,
+    Scalar, SupportedIndex, SupportedOrder,
+};
+
+/// Flusser's invariants to convolution with any centrosymmetric point-spread function, e.g. defocus or motion blur.
+///
+/// The invariants are defined recursively for odd `p + q` as
+/// `C(p, q) = μ_pq - 1 / μ_00 * Σ_n Σ_m binomial(p, n) * binomial(q, m) * C(p - n, q - m) * μ_nm`
+/// where the sums run over `0 <= n <= p` and `0 <= m <= q` with `n + m` even and `0 < n + m`.
+/// As `C(p, q)` vanishes for the first order, they cover `3 <= p + q <= order` with odd degrees only.
+/// They are ordered by ascending degree `p + q` first and ascending `p` second,
+/// i.e. up to the third order, this yields `[C(0, 3), C(1, 2), C(2, 1), C(3, 0)]`.
+///
+/// The point-spread function is expected to preserve the brightness, i.e. to sum up to 1.
+/// Calculated from [`Central`] moments, the invariants are additionally translation invariant.
+/// Calculated from [`NormalizedCentral`] moments, they are additionally scale invariant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Blur<T: Scalar> {
+    order: usize,
+    invariants: Vec<T>,
+}
+
+impl<T: Scalar> Blur<T> {
+    /// The order up to which the invariants were calculated.
+    #[inline(always)]
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// The invariants in the documented order, i.e. for using them as a feature vector.
+    #[inline(always)]
+    pub fn invariants(&self) -> &[T] {
+        &self.invariants
+    }
+
+    /// Get the invariant `C(p, q)`, if `p + q` is odd and covered by the order.
+    pub fn get(&self, p: usize, q: usize) -> Option<T> {
+        if p > self.order || q > self.order - p {
+            return None;
+        }
+
+        let degree = p + q;
+        match degree >= 3 && degree % 2 == 1 {
+            // The odd degrees 3, 5, ..., degree - 2 contribute 4 + 6 + ... + (degree - 1) invariants
+            true => Some(self.invariants[(degree - 1) * (degree + 1) / 4 - 2 + p]),
+            false => None,
+        }
+    }
+
+    /// Calculate the invariants up to a specific order.
+    /// `moments` must contain the central moments of an order >= order.
+    fn calculate<S: Storage<T>>(moments: &S, order: usize) -> Self {
+        let m00 = moments.at(0, 0);
+        let size = (order + 1) * (order + 1);
+        let mut values = vec![T::ZERO; size];
+        if m00.abs() > T::EPSILON {
+            for degree in (1..=order).step_by(2) {
+                for p in 0..=degree {
+                    let q = degree - p;
+                    let mut sum = T::ZERO;
+                    for n in 0..=p {
+                        for m in (n % 2..=q).step_by(2).filter(|m| n + m > 0) {
+                            let factor = binomial::<T>(p, n) * binomial(q, m);
+                            let previous = values[(q - m) * (order + 1) + p - n];
+                            sum = (factor * previous).mul_add(moments.at(n, m), sum);
+                        }
+                    }
+                    values[q * (order + 1) + p] = moments.at(p, q) - sum / m00;
+                }
+            }
+        }
+
+        let mut invariants = Vec::new();
+        for degree in (3..=order).step_by(2) {
+            for p in 0..=degree {
+                invariants.push(values[(degree - p) * (order + 1) + p]);
+            }
+        }
+
+        Blur { order, invariants }
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> From<&'a Central<T, ORDER>> for Blur<T>
+where
+    Order<ORDER>: SupportedOrder<T>,
+    Index<3, 0>: SupportedIndex<ORDER>,
+{
+    fn from(moments: &'a Central<T, ORDER>) -> Self {
+        Self::calculate(&moments.0, ORDER)
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> From<&'a NormalizedCentral<T, ORDER>> for Blur<T>
+where
+    Order<ORDER>: SupportedOrder<T>,
+    Index<3, 0>: SupportedIndex<ORDER>,
+{
+    fn from(moments: &'a NormalizedCentral<T, ORDER>) -> Self {
+        Self::calculate(&moments.0, ORDER)
+    }
+}
+
+impl<'a, T: Scalar> TryFrom<&'a DynCentral<T>> for Blur<T> {
+    type Error = InsufficientOrder;
+
+    /// Calculate the invariants, which requires moments of at least the third order.
+    fn try_from(moments: &'a DynCentral<T>) -> Result<Self, Self::Error> {
+        InsufficientOrder::check(3, moments.order())?;
+        Ok(Self::calculate(&moments.0, moments.order()))
+    }
+}
+
+impl<'a, T: Scalar> TryFrom<&'a DynNormalizedCentral<T>> for Blur<T> {
+    type Error = InsufficientOrder;
+
+    /// Calculate the invariants, which requires moments of at least the third order.
+    fn try_from(moments: &'a DynNormalizedCentral<T>) -> Result<Self, Self::Error> {
+        InsufficientOrder::check(3, moments.order())?;
+        Ok(Self::calculate(&moments.0, moments.order()))
+    }
+}
+
+/// Flusser's combined invariants to rotation and convolution with any centrosymmetric point-spread function.
+///
+/// The blur invariants `K(p, q)` follow the recursion of [`Blur`] with the complex moments `c_pq` instead of `μ_pq`.
+/// Like in [`Flusser`](crate::Flusser), the rotation is normalized as `Ψ(p, q) = K(p, q) * K(1, 2)^(p - q)`
+/// for `p > q` and odd degrees `3 <= p + q <= order`.
+/// They are ordered by ascending degree `p + q` first and ascending `p` second.
+/// The base `Ψ(2, 1)` is real and contributes one value, all others contribute their real part followed by their imaginary part.
+/// Up to the third order, this yields `[Ψ(2, 1), Re Ψ(3, 0), Im Ψ(3, 0)]`.
+///
+/// Calculated from [`Central`] moments, the invariants are additionally translation invariant.
+/// Calculated from [`NormalizedCentral`] moments, they are additionally scale invariant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RotationBlur<T: Scalar> {
+    order: usize,
+    invariants: Vec<T>,
+}
+
+impl<T: Scalar> RotationBlur<T> {
+    /// The order up to which the invariants were calculated.
+    #[inline(always)]
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// The invariants in the documented order, i.e. for using them as a feature vector.
+    #[inline(always)]
+    pub fn invariants(&self) -> &[T] {
+        &self.invariants
+    }
+
+    /// Calculate the invariants up to a specific order.
+    /// `moments` must contain the central moments of an order >= max(order, 3).
+    fn calculate<S: Storage<T>>(moments: &S, order: usize) -> Self {
+        let index = |p: usize, q: usize| q * (order + 1) + p;
+        let c00 = moments.at(0, 0);
+        let mut values = vec![(T::ZERO, T::ZERO); (order + 1) * (order + 1)];
+        if c00.abs() > T::EPSILON {
+            // Only the complex moments of even degree are required besides the one being calculated
+            let mut complex = vec![(T::ZERO, T::ZERO); (order + 1) * (order + 1)];
+            for degree in (2..order).step_by(2) {
+                for p in 0..=degree {
+                    complex[index(p, degree - p)] =
+                        calculate_complex_moment(moments, p, degree - p);
+                }
+            }
+
+            for degree in (1..=order).step_by(2) {
+                for p in 0..=degree {
+                    let q = degree - p;
+                    let mut sum = (T::ZERO, T::ZERO);
+                    for n in 0..=p {
+                        for m in (n % 2..=q).step_by(2).filter(|m| n + m > 0) {
+                            let factor = binomial::<T>(p, n) * binomial(q, m);
+                            let term =
+                                complex_mul(values[index(p - n, q - m)], complex[index(n, m)]);
+                            sum.0 = factor.mul_add(term.0, sum.0);
+                            sum.1 = factor.mul_add(term.1, sum.1);
+                        }
+                    }
+
+                    // The complex moment c_00 equals the real μ_00
+                    let c_pq = calculate_complex_moment(moments, p, q);
+                    values[index(p, q)] = (c_pq.0 - sum.0 / c00, c_pq.1 - sum.1 / c00);
+                }
+            }
+        }
+
+        let base = values[index(1, 2)];
+        let mut invariants = Vec::new();
+        for degree in (3..=order).step_by(2) {
+            for p in (degree + 1) / 2..=degree {
+                let q = degree - p;
+                let invariant = complex_mul(values[index(p, q)], complex_powi(base, p - q));
+                invariants.push(invariant.0);
+                if (p, q) != (2, 1) {
+                    invariants.push(invariant.1);
+                }
+            }
+        }
+
+        RotationBlur { order, invariants }
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> From<&'a Central<T, ORDER>> for RotationBlur<T>
+where
+    Order<ORDER>: SupportedOrder<T>,
+    Index<3, 0>: SupportedIndex<ORDER>,
+{
+    fn from(moments: &'a Central<T, ORDER>) -> Self {
+        Self::calculate(&moments.0, ORDER)
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> From<&'a NormalizedCentral<T, ORDER>> for RotationBlur<T>
+where
+    Order<ORDER>: SupportedOrder<T>,
+    Index<3, 0>: SupportedIndex<ORDER>,
+{
+    fn from(moments: &'a NormalizedCentral<T, ORDER>) -> Self {
+        Self::calculate(&moments.0, ORDER)
+    }
+}
+
+impl<'a, T: Scalar> TryFrom<&'a DynCentral<T>> for RotationBlur<T> {
+    type Error = InsufficientOrder;
+
+    /// Calculate the invariants, which requires moments of at least the third order.
+    fn try_from(moments: &'a DynCentral<T>) -> Result<Self, Self::Error> {
+        InsufficientOrder::check(3, moments.order())?;
+        Ok(Self::calculate(&moments.0, moments.order()))
+    }
+}
+
+impl<'a, T: Scalar> TryFrom<&'a DynNormalizedCentral<T>> for RotationBlur<T> {
+    type Error = InsufficientOrder;
+
+    /// Calculate the invariants, which requires moments of at least the third order.
+    fn try_from(moments: &'a DynNormalizedCentral<T>) -> Result<Self, Self::Error> {
+        InsufficientOrder::check(3, moments.order())?;
+        Ok(Self::calculate(&moments.0, moments.order()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{
+        Blur, Central, DynCentral, DynSpatial, GrayImage, Moments, NormalizedCentral, RotationBlur,
+        Spatial,
+    };
+
+    const SIZE: usize = 40;
+
+    /// A reproducible, irregular pattern surrounded by a black border of 8 pixels.
+    fn pattern() -> Vec<f64> {
+        (0..SIZE * SIZE)
+            .map(|index| {
+                let (x, y) = (index % SIZE, index / SIZE);
+                match (8..SIZE - 8).contains(&x) && (8..SIZE - 12).contains(&y) {
+                    true => ((index * 7919 + x * y) % 256) as f64 / 255.0,
+                    false => 0.0,
+                }
+            })
+            .collect()
+    }
+
+    /// Convolve the pixels with a centrosymmetric but otherwise irregular 5x5 kernel summing up to 1.
+    fn blur(pixels: &[f64]) -> Vec<f64> {
+        let mut kernel = [0.0; 25];
+        for (index, weight) in [1.0, 3.0, 0.0, 2.0, 5.0, 0.5, 4.0, 1.0, 2.0, 0.0, 1.5, 6.0]
+            .iter()
+            .enumerate()
+        {
+            kernel[index] = *weight;
+            kernel[24 - index] = *weight;
+        }
+        kernel[12] = 7.0;
+        let total: f64 = kernel.iter().sum();
+
+        let mut output = vec![0.0; SIZE * SIZE];
+        for y in 2..SIZE - 2 {
+            for x in 2..SIZE - 2 {
+                for (index, weight) in kernel.iter().enumerate() {
+                    let (dx, dy) = (index % 5, index / 5);
+                    output[y * SIZE + x] +=
+                        weight / total * pixels[(y + dy - 2) * SIZE + x + dx - 2];
+                }
+            }
+        }
+        output
+    }
+
+    fn moments(pixels: &[f64]) -> NormalizedCentral<f64, 7> {
+        let image = GrayImage::new(SIZE, SIZE, pixels).unwrap();
+        NormalizedCentral::from(&Spatial::<f64, 7>::from_image(&image))
+    }
+
+    #[test]
+    fn test_blur() {
+        let pixels = pattern();
+        let blurred = blur(&pixels);
+        let (original, blurred) = (moments(&pixels), moments(&blurred));
+        assert!((original.get::<2, 0>() - blurred.get::<2, 0>()).abs() > 10e-4);
+
+        let expected = Blur::from(&original);
+        let actual = Blur::from(&blurred);
+        assert_eq!(expected.invariants().len(), 4 + 6 + 8);
+        for (expected, actual) in expected.invariants().iter().zip(actual.invariants()) {
+            assert_abs_diff_eq!(*expected, *actual, epsilon = 10e-9);
+        }
+    }
+
+    #[test]
+    fn test_rotation_and_blur() {
+        // Rotating the pixels by 90 degrees after blurring them keeps the invariants
+        let pixels = pattern();
+        let blurred = blur(&pixels);
+        let mut rotated = vec![0.0; SIZE * SIZE];
+        for y in 0..SIZE {
+            for x in 0..SIZE {
+                rotated[x * SIZE + (SIZE - 1 - y)] = blurred[y * SIZE + x];
+            }
+        }
+
+        let expected = RotationBlur::from(&moments(&pixels));
+        let actual = RotationBlur::from(&moments(&rotated));
+        assert_eq!(expected.order(), 7);
+        assert_eq!(expected.invariants().len(), 3 + 6 + 8);
+        for (expected, actual) in expected.invariants().iter().zip(actual.invariants()) {
+            assert_abs_diff_eq!(*expected, *actual, epsilon = 10e-9);
+        }
+
+        // The plain blur invariants are not rotation invariant
+        let blur = Blur::from(&moments(&pixels));
+        assert!(
+            (blur.get(3, 0).unwrap() - Blur::from(&moments(&rotated)).get(3, 0).unwrap()).abs()
+                > 10e-6
+        );
+    }
+
+    #[test]
+    fn test_low_order() {
+        // Up to the third order, the invariants equal the central moments,
+        // e.g. μ03 = ab⁴ / 270 and μ21 = -a³b² / 540 of a right triangle with legs of 3 and 4
+        let points = [(10.0, 20.0), (10.0, 24.0), (13.0, 20.0)];
+        let moments = Central::from(&points.iter().collect::<Spatial<f64, 3>>());
+        let invariants = Blur::from(&moments);
+        assert_abs_diff_eq!(invariants.get(0, 3).unwrap(), 128.0 / 45.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(invariants.get(2, 1).unwrap(), -4.0 / 5.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(
+            invariants.get(1, 2).unwrap(),
+            moments.get::<1, 2>(),
+            epsilon = 10e-9
+        );
+        assert!(invariants.get(2, 0).is_none());
+        assert!(invariants.get(3, 2).is_none());
+        assert!(invariants.get(usize::MAX, 2).is_none());
+
+        let dynamic = DynCentral::from(&DynSpatial::from_points(2, points.iter()));
+        assert!(Blur::try_from(&dynamic).is_err());
+        assert!(RotationBlur::try_from(&dynamic).is_err());
+    }
+
+    #[test]
+    fn test_centrosymmetric() {
+        // All invariants vanish for centrosymmetric shapes, which cannot be told apart from blurred ones
+        let points = [(3.0, 5.0), (3.0, 8.0), (10.0, 8.0), (10.0, 5.0)];
+        let moments = Central::from(&points.iter().collect::<Spatial<f64, 7>>());
+        for invariant in Blur::from(&moments).invariants() {
+            assert_abs_diff_eq!(*invariant, 0.0, epsilon = 10e-6);
+        }
+        for invariant in RotationBlur::from(&moments).invariants() {
+            assert_abs_diff_eq!(*invariant, 0.0, epsilon = 10e-6);
+        }
+    }
+}
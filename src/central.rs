@@ -1,24 +1,37 @@
 use crate::{
-    implementation::{CentralMoments, SealedSupportedOrder, Storage},
-    Index, Moments, Order, Scalar, Spatial, SupportedIndex, SupportedOrder,
+    implementation::{
+        calculate_centroid, CentralMoments, FixedStorage, SealedSupportedOrder, Storage,
+    },
+    Centroid, Index, InertiaTensor, Iter, Moments, Order, Scalar, Spatial, SupportedIndex,
+    SupportedOrder,
 };
 
 /// The central moments of an image or contour which are translational invariant.
-#[derive(Debug, Clone, PartialEq)]
+/// They keep the centroid they were calculated around, such that the removed translation is still available.
+/// However, the centroid is ignored when comparing moments, such that the same shape at different positions compares equal.
+#[derive(Debug, Clone)]
 pub struct Central<T: Scalar, const ORDER: usize>(
     pub(crate) <Order<ORDER> as SealedSupportedOrder<T>>::Storage,
+    pub(crate) Option<Centroid<T>>,
 )
 where
     Order<ORDER>: SupportedOrder<T>;
 
+impl<T: Scalar, const ORDER: usize> PartialEq for Central<T, ORDER>
+where
+    Order<ORDER>: SupportedOrder<T>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
 impl<'a, T: Scalar, const ORDER: usize> From<&'a Spatial<T, ORDER>> for Central<T, ORDER>
 where
     Order<ORDER>: SupportedOrder<T>,
 {
     fn from(raw_moments: &'a Spatial<T, ORDER>) -> Self {
-        let mut central_moments = <Order<ORDER> as SealedSupportedOrder<T>>::Storage::zeros();
-        Order::<ORDER>::calculate_central_moments(&raw_moments.0, &mut central_moments);
-        Self(central_moments)
+        Self::with_centroid(raw_moments, calculate_centroid(&raw_moments.0))
     }
 }
 
@@ -35,11 +48,95 @@ where
     }
 }
 
+impl<T: Scalar, const ORDER: usize> Central<T, ORDER>
+where
+    Order<ORDER>: SupportedOrder<T>,
+{
+    /// Iterate over all moments as `(i, j, value)` triples in the order documented at [`Iter`].
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.0.as_slice(), ORDER)
+    }
+
+    /// Collect all moments into a vector, i.e. for using them as a feature vector.
+    /// The moments are ordered as documented at [`Iter`].
+    #[inline(always)]
+    pub fn to_vec(&self) -> Vec<T> {
+        self.0.as_slice().to_vec()
+    }
+
+    /// The centroid the moments were calculated around.
+    /// It is `None` for moments of order 0 and for degenerate contours or images without any area,
+    /// whose central moments are calculated around the origin instead.
+    #[inline(always)]
+    pub fn centroid(&self) -> Option<Centroid<T>> {
+        self.1
+    }
+
+    /// The second-moment (inertia) tensor around the centroid, which requires moments of at least the second order.
+    #[inline(always)]
+    pub fn inertia_tensor(&self) -> InertiaTensor<T>
+    where
+        Index<2, 0>: SupportedIndex<ORDER>,
+    {
+        InertiaTensor::new(&self.0, self.1)
+    }
+
+    /// Calculate the central moments given the already known centroid of the spatial moments.
+    pub(crate) fn with_centroid(
+        raw_moments: &Spatial<T, ORDER>,
+        centroid: Option<Centroid<T>>,
+    ) -> Self {
+        let mut central_moments = <Order<ORDER> as SealedSupportedOrder<T>>::Storage::zeros();
+        Order::<ORDER>::calculate_central_moments(
+            &raw_moments.0,
+            &mut central_moments,
+            centroid.map_or((T::ZERO, T::ZERO), Into::into),
+        );
+        Self(central_moments, centroid)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_abs_diff_eq;
 
-    use crate::{central::Central, Moments, Spatial};
+    use crate::{central::Central, Centroid, Moments, Spatial};
+
+    #[test]
+    fn test_higher_orders() {
+        // The rectangle [1, 5] x [2, 4] is symmetric around its centroid (3, 3)
+        let points = [(1.0, 2.0), (5.0, 2.0), (5.0, 4.0), (1.0, 4.0)];
+        let central_moments = {
+            let moments: Spatial<f64, 5> = points.iter().collect();
+            Central::from(&moments)
+        };
+
+        assert_abs_diff_eq!(central_moments.get::<0, 0>(), 8.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(central_moments.get::<4, 0>(), 128.0 / 5.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(central_moments.get::<2, 2>(), 32.0 / 9.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(central_moments.get::<0, 4>(), 8.0 / 5.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(central_moments.get::<3, 1>(), 0.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(central_moments.get::<4, 1>(), 0.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(central_moments.get::<0, 5>(), 0.0, epsilon = 10e-9);
+
+        let centroid = central_moments.centroid().unwrap();
+        assert_abs_diff_eq!(centroid.x, 3.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(centroid.y, 3.0, epsilon = 10e-9);
+    }
+
+    #[test]
+    fn test_equality() {
+        // The same shape at another position has the same moments, only the centroid differs
+        let points = [(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (0.0, 2.0)];
+        let moments = Central::from(&points.iter().collect::<Spatial<f64, 4>>());
+        let mut translated = moments.clone();
+        translated.1 = Some(Centroid { x: 10.0, y: 17.0 });
+        assert_eq!(moments, translated);
+
+        translated.0[3] += 1.0;
+        assert_ne!(moments, translated);
+    }
 
     #[test]
     fn test_central_moments() {
@@ -0,0 +1,29 @@
+use crate::{Point, Scalar};
+
+/// The centroid `(m10 / m00, m01 / m00)` of an image or contour, i.e. its center of mass.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Centroid<T: Scalar> {
+    /// The x coordinate of the centroid.
+    pub x: T,
+    /// The y coordinate of the centroid.
+    pub y: T,
+}
+
+impl<T: Scalar> Point<T> for Centroid<T> {
+    #[inline(always)]
+    fn x(&self) -> T {
+        self.x
+    }
+
+    #[inline(always)]
+    fn y(&self) -> T {
+        self.y
+    }
+}
+
+impl<T: Scalar> From<Centroid<T>> for (T, T) {
+    #[inline(always)]
+    fn from(centroid: Centroid<T>) -> Self {
+        (centroid.x, centroid.y)
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{
+    implementation::{calculate_complex_moment, FixedStorage, SealedSupportedOrder, Storage},
+    Central, Index, NormalizedCentral, Order, Scalar, Spatial, SupportedIndex, SupportedOrder,
+};
+
+/// The complex moments `c_pq = ∫∫ (x + iy)^p (x - iy)^q f(x, y) dx dy` of an image or contour.
+///
+/// Calculated from [`Spatial`] moments they depend on the position of the shape, while those
+/// calculated from [`Central`] or [`NormalizedCentral`] moments share their invariants.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Complex<T: Scalar, const ORDER: usize>
+where
+    Order<ORDER>: SupportedOrder<T>,
+{
+    real: <Order<ORDER> as SealedSupportedOrder<T>>::Storage,
+    imaginary: <Order<ORDER> as SealedSupportedOrder<T>>::Storage,
+}
+
+impl<T: Scalar, const ORDER: usize> Complex<T, ORDER>
+where
+    Order<ORDER>: SupportedOrder<T>,
+{
+    /// Get the moment c_pq as (real, imaginary) pair at a specific position checked at compile time.
+    #[inline(always)]
+    pub fn get<const P: usize, const Q: usize>(&self) -> (T, T)
+    where
+        Index<P, Q>: SupportedIndex<ORDER>,
+    {
+        (self.real.get::<P, Q>(), self.imaginary.get::<P, Q>())
+    }
+
+    /// Calculate the complex moments from geometric moments of the same order.
+    fn calculate<S: Storage<T>>(moments: &S) -> Self {
+        let mut real = <Order<ORDER> as SealedSupportedOrder<T>>::Storage::zeros();
+        let mut imaginary = <Order<ORDER> as SealedSupportedOrder<T>>::Storage::zeros();
+        for q in 0..=ORDER {
+            for p in 0..=(ORDER - q) {
+                let (re, im) = calculate_complex_moment(moments, p, q);
+                *real.at_mut(p, q) = re;
+                *imaginary.at_mut(p, q) = im;
+            }
+        }
+        Complex { real, imaginary }
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> From<&'a Spatial<T, ORDER>> for Complex<T, ORDER>
+where
+    Order<ORDER>: SupportedOrder<T>,
+{
+    fn from(moments: &'a Spatial<T, ORDER>) -> Self {
+        Self::calculate(&moments.0)
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> From<&'a Central<T, ORDER>> for Complex<T, ORDER>
+where
+    Order<ORDER>: SupportedOrder<T>,
+{
+    fn from(moments: &'a Central<T, ORDER>) -> Self {
+        Self::calculate(&moments.0)
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> From<&'a NormalizedCentral<T, ORDER>> for Complex<T, ORDER>
+where
+    Order<ORDER>: SupportedOrder<T>,
+{
+    fn from(moments: &'a NormalizedCentral<T, ORDER>) -> Self {
+        Self::calculate(&moments.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Central, Complex, Moments, Spatial};
+
+    #[test]
+    fn test_complex_moments() {
+        // A clockwise right triangle with legs of 3 and 4, whose central moments are known in closed form
+        let points = [(10.0, 20.0), (10.0, 24.0), (13.0, 20.0)];
+        let central = Central::from(&points.iter().collect::<Spatial<f64, 3>>());
+        let complex = Complex::from(&central);
+
+        assert_eq!(complex.get::<0, 0>(), (central.get::<0, 0>(), 0.0));
+        assert_eq!(complex.get::<1, 0>(), (0.0, 0.0));
+
+        // c11 = μ20 + μ02 and c20 = μ20 - μ02 + 2iμ11
+        assert_abs_diff_eq!(complex.get::<1, 1>().0, 25.0 / 3.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(complex.get::<1, 1>().1, 0.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(complex.get::<2, 0>().0, -7.0 / 3.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(complex.get::<2, 0>().1, -4.0, epsilon = 10e-9);
+
+        // c30 = μ30 - 3μ12 + i(3μ21 - μ03) and c21 = μ30 + μ12 + i(μ21 + μ03)
+        assert_abs_diff_eq!(complex.get::<3, 0>().0, 22.0 / 5.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(complex.get::<3, 0>().1, -236.0 / 45.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(complex.get::<2, 1>().0, 2.0 / 15.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(complex.get::<2, 1>().1, 92.0 / 45.0, epsilon = 10e-9);
+
+        // c_qp is the complex conjugate of c_pq
+        assert_abs_diff_eq!(complex.get::<1, 2>().0, complex.get::<2, 1>().0);
+        assert_abs_diff_eq!(complex.get::<1, 2>().1, -complex.get::<2, 1>().1);
+        assert_abs_diff_eq!(complex.get::<0, 3>().0, complex.get::<3, 0>().0);
+        assert_abs_diff_eq!(complex.get::<0, 3>().1, -complex.get::<3, 0>().1);
+    }
+
+    #[test]
+    fn test_rotation() {
+        // Rotating the shape by θ multiplies c_pq by exp(i(p - q)θ), no matter around which point,
+        // only the central moments of the far away rotated shape lose some precision
+        let points = [(10.0, 20.0), (10.0, 24.0), (13.0, 20.0)];
+        let complex = Complex::from(&Central::from(&points.iter().collect::<Spatial<f64, 3>>()));
+
+        for &angle in &[0.3f64, std::f64::consts::FRAC_PI_2, -2.0] {
+            let (sin, cos) = angle.sin_cos();
+            let rotated: Vec<_> = points
+                .iter()
+                .map(|(x, y)| (x - 50.0, y + 20.0))
+                .map(|(x, y)| (cos * x - sin * y + 50.0, sin * x + cos * y - 20.0))
+                .collect();
+            let rotated =
+                Complex::from(&Central::from(&rotated.iter().collect::<Spatial<f64, 3>>()));
+
+            let (re, im) = complex.get::<3, 0>();
+            let (sin3, cos3) = (3.0 * angle).sin_cos();
+            assert_abs_diff_eq!(
+                rotated.get::<3, 0>().0,
+                re * cos3 - im * sin3,
+                epsilon = 10e-7
+            );
+            assert_abs_diff_eq!(
+                rotated.get::<3, 0>().1,
+                re * sin3 + im * cos3,
+                epsilon = 10e-7
+            );
+
+            let (re, im) = complex.get::<2, 1>();
+            assert_abs_diff_eq!(
+                rotated.get::<2, 1>().0,
+                re * cos - im * sin,
+                epsilon = 10e-7
+            );
+            assert_abs_diff_eq!(
+                rotated.get::<2, 1>().1,
+                re * sin + im * cos,
+                epsilon = 10e-7
+            );
+
+            // c11 has p = q and is therefore invariant
+            assert_abs_diff_eq!(
+                rotated.get::<1, 1>().0,
+                complex.get::<1, 1>().0,
+                epsilon = 10e-7
+            );
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use std::convert::TryFrom;
+
+use crate::{
+    implementation::{principal_variances, standardized_moment, Storage},
+    Central, DynCentral, DynNormalizedCentral, Index, InsufficientOrder, NormalizedCentral, Order,
+    Scalar, SupportedIndex, SupportedOrder,
+};
+
+/// The direction of a shape over the full circle, resolving the 180 degree ambiguity of the orientation.
+///
+/// The second-order moments only define the axis `0.5 * atan2(2μ11, μ20 - μ02)` of the major principal axis.
+/// Among both directions along this axis, the one with the positive skewness `μ'30 / (μ00 λ1^(3/2))` is chosen
+/// like [`ShapeDescriptors::skewness`](crate::ShapeDescriptors::skewness),
+/// where `μ'30` is the third-order central moment along the axis and `λ1` the variance along it.
+/// Thus, the direction points from the bulk of the mass towards the thinner, longer tail, e.g. towards the tip of a teardrop.
+///
+/// Like [`Ellipse::orientation`](crate::Ellipse::orientation), the angle is measured from the positive x-axis
+/// towards the positive y-axis, i.e. clockwise on screen as the y-axis points downwards in images.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Direction<T: Scalar> {
+    /// The angle of the direction in radians within (-π, π].
+    pub angle: T,
+    /// The confidence `|skewness| * (1 - λ2 / λ1)` of the direction, which is invariant under translation, rotation and scale.
+    /// It vanishes for shapes without a preferred direction: Both centrosymmetric shapes like rectangles
+    /// without any skewness and shapes without a principal axis like equilateral triangles with `λ1 == λ2`.
+    /// Values close to 0 indicate that noise may flip the direction or rotate it arbitrarily.
+    pub confidence: T,
+}
+
+impl<T: Scalar> Direction<T> {
+    /// The unit vector `(cos, sin)` of the direction.
+    #[inline(always)]
+    pub fn vector(&self) -> (T, T) {
+        (self.angle.cos(), self.angle.sin())
+    }
+
+    /// Calculate the direction from the central moments or the normalized central moments of an order >= 3.
+    /// For degenerate contours or images without any area, both the angle and the confidence are 0.
+    fn calculate<S: Storage<T>>(moments: &S) -> Self {
+        let mut direction = Direction {
+            angle: T::ZERO,
+            confidence: T::ZERO,
+        };
+        let (major, minor, angle) = match principal_variances(
+            moments.at(0, 0),
+            moments.at(2, 0),
+            moments.at(1, 1),
+            moments.at(0, 2),
+        ) {
+            Some((major, minor, angle)) if major > T::EPSILON => (major, minor, angle),
+            _ => return direction,
+        };
+
+        let skewness = standardized_moment(moments, 3, 0, (angle.sin(), angle.cos()), major.sqrt());
+        direction.angle = match (skewness < T::ZERO, angle > T::ZERO) {
+            (false, _) => angle,
+            (true, true) => angle - T::PI,
+            (true, false) => angle + T::PI,
+        };
+        direction.confidence = skewness.abs() * (T::ONE - minor / major);
+        direction
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> From<&'a Central<T, ORDER>> for Direction<T>
+where
+    Order<ORDER>: SupportedOrder<T>,
+    Index<3, 0>: SupportedIndex<ORDER>,
+{
+    fn from(moments: &'a Central<T, ORDER>) -> Self {
+        Self::calculate(&moments.0)
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> From<&'a NormalizedCentral<T, ORDER>> for Direction<T>
+where
+    Order<ORDER>: SupportedOrder<T>,
+    Index<3, 0>: SupportedIndex<ORDER>,
+{
+    fn from(moments: &'a NormalizedCentral<T, ORDER>) -> Self {
+        Self::calculate(&moments.0)
+    }
+}
+
+impl<'a, T: Scalar> TryFrom<&'a DynCentral<T>> for Direction<T> {
+    type Error = InsufficientOrder;
+
+    /// Calculate the direction, which requires moments of at least the third order.
+    fn try_from(moments: &'a DynCentral<T>) -> Result<Self, Self::Error> {
+        InsufficientOrder::check(3, moments.order())?;
+        Ok(Self::calculate(&moments.0))
+    }
+}
+
+impl<'a, T: Scalar> TryFrom<&'a DynNormalizedCentral<T>> for Direction<T> {
+    type Error = InsufficientOrder;
+
+    /// Calculate the direction, which requires moments of at least the third order.
+    fn try_from(moments: &'a DynNormalizedCentral<T>) -> Result<Self, Self::Error> {
+        InsufficientOrder::check(3, moments.order())?;
+        Ok(Self::calculate(&moments.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Central, Direction, DynCentral, DynSpatial, NormalizedCentral, Spatial};
+
+    fn direction(points: &[(f64, f64)], angle: f64) -> Direction<f64> {
+        let (sin, cos) = angle.sin_cos();
+        let moments: Spatial<f64, 3> = points
+            .iter()
+            .map(|(x, y)| (x * cos - y * sin + 5.0, x * sin + y * cos - 2.0))
+            .collect();
+        Direction::from(&Central::from(&moments))
+    }
+
+    #[test]
+    fn test_full_circle() {
+        // An isosceles triangle with its tip pointing towards the positive x-axis
+        let triangle = [(0.0, -1.0), (6.0, 0.0), (0.0, 1.0)];
+        let expected = direction(&triangle, 0.0);
+        assert_abs_diff_eq!(expected.angle, 0.0, epsilon = 10e-9);
+        assert!(expected.confidence > 0.1);
+
+        for angle in [0.3, 1.4, 2.0, 3.0, -0.5, -1.7, -3.0] {
+            let actual = direction(&triangle, angle);
+            assert_abs_diff_eq!(actual.angle, angle, epsilon = 10e-9);
+            assert_abs_diff_eq!(actual.confidence, expected.confidence, epsilon = 10e-9);
+            assert_abs_diff_eq!(actual.vector().0, angle.cos(), epsilon = 10e-9);
+        }
+
+        // Mirroring the triangle at the y-axis lets it point towards the negative x-axis
+        let mirrored: Vec<_> = triangle.iter().map(|(x, y)| (-x, *y)).collect();
+        assert_abs_diff_eq!(
+            direction(&mirrored, 0.0).angle,
+            std::f64::consts::PI,
+            epsilon = 10e-9
+        );
+    }
+
+    #[test]
+    fn test_confidence() {
+        // Neither centrosymmetric shapes nor shapes without a principal axis have a preferred direction
+        let rectangle = [(0.0, 0.0), (4.0, 0.0), (4.0, 1.0), (0.0, 1.0)];
+        assert_abs_diff_eq!(direction(&rectangle, 0.7).confidence, 0.0, epsilon = 10e-9);
+        let height = 3f64.sqrt();
+        let equilateral = [(0.0, 0.0), (2.0, 0.0), (1.0, height)];
+        assert_abs_diff_eq!(
+            direction(&equilateral, 0.2).confidence,
+            0.0,
+            epsilon = 10e-9
+        );
+
+        // A slightly asymmetric shape is less confident than a clearly asymmetric one
+        let trapezoid = [(0.0, -1.0), (6.0, -0.9), (6.0, 0.9), (0.0, 1.0)];
+        let triangle = [(0.0, -1.0), (6.0, 0.0), (0.0, 1.0)];
+        let weak = direction(&trapezoid, 0.0);
+        assert!(weak.confidence > 0.0);
+        assert!(weak.confidence < 0.1 * direction(&triangle, 0.0).confidence);
+
+        // The normalization of the moments does not change the direction
+        let moments: Spatial<f64, 3> = triangle.iter().collect();
+        let normalized = Direction::from(&NormalizedCentral::from(&moments));
+        assert_abs_diff_eq!(normalized.angle, 0.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(
+            normalized.confidence,
+            direction(&triangle, 0.0).confidence,
+            epsilon = 10e-9
+        );
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let empty = direction(&[], 0.0);
+        assert_eq!((empty.angle, empty.confidence), (0.0, 0.0));
+
+        let moments = DynCentral::from(&DynSpatial::from_points(
+            2,
+            [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)],
+        ));
+        assert!(Direction::try_from(&moments).is_err());
+    }
+}
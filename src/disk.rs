@@ -0,0 +1,133 @@
+use crate::{implementation::UnitDisk, Image, Scalar};
+
+/// The way the pixels of an image are mapped into the unit disk required by circular moments.
+/// In all cases, pixels are sampled at their centers and those outside the disk are ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiskMapping {
+    /// The disk is inscribed in the image and centered at its center, ignoring the corners of the image.
+    #[default]
+    Inscribed,
+    /// The disk is circumscribed about the image and centered at its center, such that every pixel is considered.
+    Circumscribed,
+    /// The disk is centered at the centroid of the intensities and its radius is `2 * sqrt(2 * (μ20 + μ02) / μ00)`,
+    /// i.e. twice the radius of a uniform disk with the same spread of the intensities around its centroid.
+    /// This makes the moments translation and scale invariant, as long as the object lies within the disk.
+    /// Unlike a radius derived from the mass `μ00`, the spread does not depend on the scale of the intensities,
+    /// e.g. grayscale images within 0..255 and 0..1 are mapped onto the same disk.
+    /// Images without mass or spread fall back to [`DiskMapping::Inscribed`].
+    Centroid,
+}
+
+impl DiskMapping {
+    /// Calculate the disk for an image.
+    pub(crate) fn unit_disk<T: Scalar, I: Image<T>>(self, image: &I) -> UnitDisk<T> {
+        let (width, height) = (T::from_usize(image.width()), T::from_usize(image.height()));
+        let center = (width * T::F1_2, height * T::F1_2);
+        match self {
+            DiskMapping::Inscribed => UnitDisk {
+                center,
+                radius: match width < height {
+                    true => width,
+                    false => height,
+                } * T::F1_2,
+            },
+            DiskMapping::Circumscribed => UnitDisk {
+                center,
+                radius: width.mul_add(width, height * height).sqrt() * T::F1_2,
+            },
+            DiskMapping::Centroid => {
+                let (mut m00, mut m10, mut m01, mut m20, mut m02) =
+                    (T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ZERO);
+                for y in 0..image.height() {
+                    let n_y = T::from_usize(y) + T::F1_2;
+                    for x in 0..image.width() {
+                        let n_x = T::from_usize(x) + T::F1_2;
+                        let intensity = image.intensity(x, y);
+                        m00 += intensity;
+                        m10 = intensity.mul_add(n_x, m10);
+                        m01 = intensity.mul_add(n_y, m01);
+                        m20 = (intensity * n_x).mul_add(n_x, m20);
+                        m02 = (intensity * n_y).mul_add(n_y, m02);
+                    }
+                }
+                if m00 <= T::EPSILON {
+                    return DiskMapping::Inscribed.unit_disk(image);
+                }
+
+                let center = (m10 / m00, m01 / m00);
+                let spread = (m20 - m10 * center.0 + m02 - m01 * center.1) / m00;
+                match spread > T::EPSILON {
+                    true => UnitDisk {
+                        center,
+                        radius: T::TWO * (T::TWO * spread).sqrt(),
+                    },
+                    false => DiskMapping::Inscribed.unit_disk(image),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::DiskMapping;
+    use crate::GrayImage;
+
+    #[test]
+    fn test_unit_disk() {
+        let pixels = [0.0; 48];
+        let image = GrayImage::new(8, 6, &pixels[..]).unwrap();
+        let inscribed = DiskMapping::Inscribed.unit_disk::<f64, _>(&image);
+        assert_eq!(inscribed.center, (4.0, 3.0));
+        assert_abs_diff_eq!(inscribed.radius, 3.0);
+
+        let circumscribed = DiskMapping::Circumscribed.unit_disk::<f64, _>(&image);
+        assert_eq!(circumscribed.center, (4.0, 3.0));
+        assert_abs_diff_eq!(circumscribed.radius, 5.0);
+        assert_abs_diff_eq!(circumscribed.pixel_area(), 1.0 / 25.0);
+
+        // Without any mass, the centroid is undefined
+        assert_eq!(DiskMapping::Centroid.unit_disk::<f64, _>(&image), inscribed);
+    }
+
+    #[test]
+    fn test_centroid() {
+        // A block of 2 x 2 pixels in the top left corner
+        let mut pixels = [false; 48];
+        for index in [0, 1, 8, 9] {
+            pixels[index] = true;
+        }
+        let image = GrayImage::new(8, 6, &pixels[..]).unwrap();
+        let disk = DiskMapping::Centroid.unit_disk::<f64, _>(&image);
+        assert_eq!(disk.center, (1.0, 1.0));
+        // The pixel centers have a variance of 0.25 along both axes
+        assert_abs_diff_eq!(disk.radius, 2.0, epsilon = 10e-9);
+
+        // A single pixel has no spread
+        let mut pixels = [false; 48];
+        pixels[9] = true;
+        let image = GrayImage::new(8, 6, &pixels[..]).unwrap();
+        assert_eq!(
+            DiskMapping::Centroid.unit_disk::<f64, _>(&image),
+            DiskMapping::Inscribed.unit_disk(&image)
+        );
+    }
+
+    #[test]
+    fn test_intensity_scale() {
+        // The same image with intensities within 0..255 and 0..1 is mapped onto the same disk
+        let pixels: Vec<f64> = (0..48)
+            .map(|index| ((index * 7) % 11) as f64 * 23.0)
+            .collect();
+        let rescaled: Vec<f64> = pixels.iter().map(|value| value / 255.0).collect();
+        let expected =
+            DiskMapping::Centroid.unit_disk::<f64, _>(&GrayImage::new(8, 6, &pixels[..]).unwrap());
+        let actual = DiskMapping::Centroid
+            .unit_disk::<f64, _>(&GrayImage::new(8, 6, &rescaled[..]).unwrap());
+        assert_abs_diff_eq!(actual.center.0, expected.center.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(actual.center.1, expected.center.1, epsilon = 10e-9);
+        assert_abs_diff_eq!(actual.radius, expected.radius, epsilon = 10e-9);
+    }
+}
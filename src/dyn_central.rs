@@ -0,0 +1,182 @@
+use std::convert::TryFrom;
+
+use crate::{
+    implementation::{
+        calculate_central_moments_dynamic, calculate_centroid, copy_truncated, DynStorage,
+        FixedStorage, SealedSupportedOrder, Storage,
+    },
+    Central, Centroid, DynSpatial, InertiaTensor, InsufficientOrder, Iter, Order, Scalar,
+    SupportedOrder,
+};
+
+/// The central moments of an image or contour which are translational invariant with an order chosen at runtime.
+/// They keep the centroid they were calculated around, such that the removed translation is still available.
+/// However, the centroid is ignored when comparing moments, such that the same shape at different positions compares equal.
+#[derive(Debug, Clone)]
+pub struct DynCentral<T: Scalar>(pub(crate) DynStorage<T>, pub(crate) Option<Centroid<T>>);
+
+impl<T: Scalar> PartialEq for DynCentral<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Scalar> DynCentral<T> {
+    /// The order up to which the moments were calculated.
+    #[inline(always)]
+    pub fn order(&self) -> usize {
+        self.0.order()
+    }
+
+    /// The centroid the moments were calculated around.
+    /// It is `None` for moments of order 0 and for degenerate contours or images without any area,
+    /// whose central moments are calculated around the origin instead.
+    #[inline(always)]
+    pub fn centroid(&self) -> Option<Centroid<T>> {
+        self.1
+    }
+
+    /// The second-moment (inertia) tensor around the centroid, which requires moments of at least the second order.
+    pub fn inertia_tensor(&self) -> Result<InertiaTensor<T>, InsufficientOrder> {
+        InsufficientOrder::check(2, self.0.order())?;
+        Ok(InertiaTensor::new(&self.0, self.1))
+    }
+
+    /// Get the moment at a specific position, if it is covered by the order.
+    #[inline(always)]
+    pub fn get(&self, i: usize, j: usize) -> Option<T> {
+        match i <= self.0.order() && j <= self.0.order() - i {
+            true => Some(self.0.at(i, j)),
+            false => None,
+        }
+    }
+
+    /// Iterate over all moments as `(i, j, value)` triples in the order documented at [`Iter`].
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.0.as_slice(), self.0.order())
+    }
+
+    /// Collect all moments into a vector ordered as documented at [`Iter`].
+    #[inline(always)]
+    pub fn to_vec(&self) -> Vec<T> {
+        self.0.as_slice().to_vec()
+    }
+}
+
+impl<'a, T: Scalar> From<&'a DynSpatial<T>> for DynCentral<T> {
+    fn from(raw_moments: &'a DynSpatial<T>) -> Self {
+        let centroid = calculate_centroid(&raw_moments.0);
+        let mut central_moments = DynStorage::zeros(raw_moments.order());
+        calculate_central_moments_dynamic(
+            &raw_moments.0,
+            &mut central_moments,
+            centroid.map_or((T::ZERO, T::ZERO), Into::into),
+        );
+        Self(central_moments, centroid)
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> From<&'a Central<T, ORDER>> for DynCentral<T>
+where
+    Order<ORDER>: SupportedOrder<T>,
+{
+    fn from(moments: &'a Central<T, ORDER>) -> Self {
+        Self(DynStorage::truncated(&moments.0, ORDER), moments.1)
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> TryFrom<&'a DynCentral<T>> for Central<T, ORDER>
+where
+    Order<ORDER>: SupportedOrder<T>,
+{
+    type Error = InsufficientOrder;
+
+    /// Convert the moments into their compile-time checked counterpart, dropping all moments of higher order.
+    fn try_from(moments: &'a DynCentral<T>) -> Result<Self, Self::Error> {
+        InsufficientOrder::check(ORDER, moments.order())?;
+
+        let mut storage = <Order<ORDER> as SealedSupportedOrder<T>>::Storage::zeros();
+        copy_truncated(&moments.0, &mut storage);
+        Ok(Self(storage, moments.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{implementation::Storage, Central, Centroid, DynCentral, DynSpatial, Spatial};
+
+    #[test]
+    fn test_get() {
+        // The rectangle [1, 5] x [2, 4] is symmetric around its centroid
+        let points = [(1.0, 2.0), (5.0, 2.0), (5.0, 4.0), (1.0, 4.0)];
+        let moments = DynCentral::from(&DynSpatial::<f64>::from_points(4, points.iter()));
+        assert_abs_diff_eq!(moments.get(1, 0).unwrap(), 0.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(moments.get(0, 4).unwrap(), 8.0 / 5.0, epsilon = 10e-9);
+        assert_eq!(moments.get(2, 3), None);
+        assert_eq!(moments.get(usize::MAX, 1), None);
+        assert_eq!(moments.get(1, usize::MAX), None);
+    }
+
+    #[test]
+    fn test_consistency() {
+        // A clockwise right triangle with legs of 3 and 4, far away from the origin
+        let points = [(100.0, 200.0), (100.0, 204.0), (103.0, 200.0)];
+        let fixed = Central::from(&points.iter().collect::<Spatial<f64, 6>>());
+
+        for order in 0..=6 {
+            let dynamic = DynCentral::from(&DynSpatial::from_points(order, points.iter()));
+            assert_eq!(dynamic.order(), order);
+            for j in 0..=order {
+                for i in 0..=(order - j) {
+                    assert_eq!(dynamic.get(i, j), DynCentral::from(&fixed).get(i, j));
+                }
+            }
+            if order >= 2 {
+                // The moments around the centroid are a³b / 36, -a²b² / 72 and ab³ / 36
+                assert_abs_diff_eq!(dynamic.get(0, 0).unwrap(), 6.0, epsilon = 10e-9);
+                assert_abs_diff_eq!(dynamic.get(2, 0).unwrap(), 3.0, epsilon = 10e-6);
+                assert_abs_diff_eq!(dynamic.get(1, 1).unwrap(), -2.0, epsilon = 10e-6);
+                assert_abs_diff_eq!(dynamic.get(0, 2).unwrap(), 16.0 / 3.0, epsilon = 10e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_conversion() {
+        let points = [(100.0, 200.0), (100.0, 204.0), (103.0, 200.0)];
+        let dynamic = DynCentral::from(&DynSpatial::from_points(3, points.iter()));
+        let fixed = Central::<f64, 3>::try_from(&dynamic).unwrap();
+        assert_eq!(DynCentral::from(&fixed), dynamic);
+        assert_eq!(fixed.centroid(), dynamic.centroid());
+        let centroid = dynamic.centroid().unwrap();
+        assert_abs_diff_eq!(centroid.x, 101.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(centroid.y, 200.0 + 4.0 / 3.0, epsilon = 10e-9);
+        assert!(Central::<f64, 4>::try_from(&dynamic).is_err());
+
+        // A contour without area has no centroid in either representation
+        let line = [(1.0, 1.0), (4.0, 4.0)];
+        let dynamic = DynCentral::from(&DynSpatial::from_points(3, line.iter()));
+        let fixed = Central::<f64, 2>::try_from(&dynamic).unwrap();
+        assert!(dynamic.centroid().is_none());
+        assert!(fixed.centroid().is_none());
+        assert_eq!(DynCentral::from(&fixed).order(), 2);
+    }
+
+    #[test]
+    fn test_equality() {
+        // The same shape at another position has the same moments, only the centroid differs
+        let points = [(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (0.0, 2.0)];
+        let moments = DynCentral::from(&DynSpatial::from_points(4, points.iter()));
+        let mut translated = moments.clone();
+        translated.1 = Some(Centroid { x: 10.0, y: 17.0 });
+        assert_eq!(moments, translated);
+
+        *translated.0.at_mut(2, 0) += 1.0;
+        assert_ne!(moments, translated);
+    }
+}
@@ -0,0 +1,133 @@
+use std::convert::TryFrom;
+
+use crate::{
+    implementation::{
+        calculate_normalized_central_moments_dynamic, copy_truncated, DynStorage, FixedStorage,
+        SealedSupportedOrder, Storage,
+    },
+    DynCentral, InsufficientOrder, Iter, NormalizedCentral, Order, Scalar, SupportedOrder,
+};
+
+/// The normalized central moments of an image or contour which are translational and scale invariant with an order chosen at runtime.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynNormalizedCentral<T: Scalar>(pub(crate) DynStorage<T>);
+
+impl<T: Scalar> DynNormalizedCentral<T> {
+    /// The order up to which the moments were calculated.
+    #[inline(always)]
+    pub fn order(&self) -> usize {
+        self.0.order()
+    }
+
+    /// Get the moment at a specific position, if it is covered by the order.
+    #[inline(always)]
+    pub fn get(&self, i: usize, j: usize) -> Option<T> {
+        match i <= self.0.order() && j <= self.0.order() - i {
+            true => Some(self.0.at(i, j)),
+            false => None,
+        }
+    }
+
+    /// Iterate over all moments as `(i, j, value)` triples in the order documented at [`Iter`].
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.0.as_slice(), self.0.order())
+    }
+
+    /// Collect all moments into a vector ordered as documented at [`Iter`].
+    #[inline(always)]
+    pub fn to_vec(&self) -> Vec<T> {
+        self.0.as_slice().to_vec()
+    }
+}
+
+impl<'a, T: Scalar> From<&'a DynCentral<T>> for DynNormalizedCentral<T> {
+    fn from(central_moments: &'a DynCentral<T>) -> Self {
+        let mut normalized_central_moments = DynStorage::zeros(central_moments.order());
+        calculate_normalized_central_moments_dynamic(
+            &central_moments.0,
+            &mut normalized_central_moments,
+        );
+        Self(normalized_central_moments)
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> From<&'a NormalizedCentral<T, ORDER>>
+    for DynNormalizedCentral<T>
+where
+    Order<ORDER>: SupportedOrder<T>,
+{
+    fn from(moments: &'a NormalizedCentral<T, ORDER>) -> Self {
+        Self(DynStorage::truncated(&moments.0, ORDER))
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> TryFrom<&'a DynNormalizedCentral<T>>
+    for NormalizedCentral<T, ORDER>
+where
+    Order<ORDER>: SupportedOrder<T>,
+{
+    type Error = InsufficientOrder;
+
+    /// Convert the moments into their compile-time checked counterpart, dropping all moments of higher order.
+    fn try_from(moments: &'a DynNormalizedCentral<T>) -> Result<Self, Self::Error> {
+        InsufficientOrder::check(ORDER, moments.order())?;
+
+        let mut storage = <Order<ORDER> as SealedSupportedOrder<T>>::Storage::zeros();
+        copy_truncated(&moments.0, &mut storage);
+        Ok(Self(storage))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{
+        Central, DynCentral, DynNormalizedCentral, DynSpatial, NormalizedCentral, Spatial,
+    };
+
+    #[test]
+    fn test_get() {
+        let points = [(1.0, 2.0), (5.0, 2.0), (5.0, 4.0), (1.0, 4.0)];
+        let moments = DynNormalizedCentral::from(&DynCentral::from(
+            &DynSpatial::<f64>::from_points(4, points.iter()),
+        ));
+        assert_eq!(moments.get(0, 0), Some(1.0));
+        assert_eq!(moments.get(2, 3), None);
+        assert_eq!(moments.get(usize::MAX, 1), None);
+        assert_eq!(moments.get(1, usize::MAX), None);
+    }
+
+    #[test]
+    fn test_consistency() {
+        // A clockwise right triangle with legs of 3 and 4, and the same triangle scaled by 5 and moved
+        let points = [(0.0, 0.0), (0.0, 4.0), (3.0, 0.0)];
+        let scaled: Vec<_> = points
+            .iter()
+            .map(|(x, y)| (5.0 * x + 40.0, 5.0 * y - 30.0))
+            .collect();
+        let fixed =
+            NormalizedCentral::from(&Central::from(&points.iter().collect::<Spatial<f64, 5>>()));
+
+        let dynamic = DynNormalizedCentral::from(&DynCentral::from(&DynSpatial::from_points(
+            5,
+            points.iter(),
+        )));
+        assert_eq!(DynNormalizedCentral::from(&fixed), dynamic);
+        assert_eq!(NormalizedCentral::<f64, 5>::try_from(&dynamic), Ok(fixed));
+        assert!(NormalizedCentral::<f64, 6>::try_from(&dynamic).is_err());
+
+        // η20 = μ20 / μ00² = (a³b / 36) / (ab / 2)²
+        assert_abs_diff_eq!(dynamic.get(2, 0).unwrap(), 1.0 / 12.0, epsilon = 10e-12);
+
+        let dynamic_scaled = DynNormalizedCentral::from(&DynCentral::from(
+            &DynSpatial::from_points(5, scaled.iter()),
+        ));
+        for ((_, _, moment), (_, _, scaled_moment)) in dynamic.iter().zip(dynamic_scaled.iter()) {
+            assert_abs_diff_eq!(moment, scaled_moment, epsilon = 10e-9);
+        }
+    }
+}
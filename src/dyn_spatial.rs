@@ -0,0 +1,206 @@
+use std::convert::TryFrom;
+
+use crate::{
+    implementation::{
+        accumulate_image, calculate_centroid, copy_truncated, Accumulator, DynStorage,
+        FixedStorage, SealedSupportedOrder, Storage,
+    },
+    Centroid, Image, InsufficientOrder, Iter, Order, Point, Scalar, Spatial, SupportedOrder,
+};
+
+/// The raw, spatial moments of an image or contour with an order chosen at runtime.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynSpatial<T: Scalar>(pub(crate) DynStorage<T>);
+
+impl<T: Scalar> DynSpatial<T> {
+    /// Calculate the moments up to a specific order from the points of a contour.
+    pub fn from_points<P: Point<T>, I: IntoIterator<Item = P>>(order: usize, points: I) -> Self {
+        let mut iterator = points.into_iter();
+        let (mut acc, first_point) = match iterator.next() {
+            Some(point) => {
+                let first_point = point.clone();
+                let acc = Accumulator::new(DynStorage::zeros(order), point);
+                (acc, first_point)
+            }
+            None => return Self(DynStorage::zeros(order)),
+        };
+
+        // Feed in all the values and "wrap around" in the end
+        for point in iterator {
+            acc.update_dynamic(point);
+        }
+        acc.update_dynamic(first_point);
+
+        // Calculate the final moments
+        Self(acc.finalize_dynamic())
+    }
+
+    /// Calculate the moments up to a specific order of a grayscale or binary image.
+    /// Like OpenCV, each pixel is located at its integer coordinates `(x, y)` weighted by its intensity.
+    pub fn from_image<I: Image<T>>(order: usize, image: &I) -> Self {
+        let mut storage = DynStorage::zeros(order);
+        accumulate_image(image, &mut storage);
+        Self(storage)
+    }
+
+    /// The order up to which the moments were calculated.
+    #[inline(always)]
+    pub fn order(&self) -> usize {
+        self.0.order()
+    }
+
+    /// The centroid `(m10 / m00, m01 / m00)`, which is `None` for moments of order 0,
+    /// degenerate contours or images without any area.
+    #[inline(always)]
+    pub fn centroid(&self) -> Option<Centroid<T>> {
+        calculate_centroid(&self.0)
+    }
+
+    /// Get the moment at a specific position, if it is covered by the order.
+    #[inline(always)]
+    pub fn get(&self, i: usize, j: usize) -> Option<T> {
+        match i <= self.0.order() && j <= self.0.order() - i {
+            true => Some(self.0.at(i, j)),
+            false => None,
+        }
+    }
+
+    /// Iterate over all moments as `(i, j, value)` triples in the order documented at [`Iter`].
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.0.as_slice(), self.0.order())
+    }
+
+    /// Collect all moments into a vector ordered as documented at [`Iter`].
+    #[inline(always)]
+    pub fn to_vec(&self) -> Vec<T> {
+        self.0.as_slice().to_vec()
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> From<&'a Spatial<T, ORDER>> for DynSpatial<T>
+where
+    Order<ORDER>: SupportedOrder<T>,
+{
+    fn from(moments: &'a Spatial<T, ORDER>) -> Self {
+        Self(DynStorage::truncated(&moments.0, ORDER))
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> TryFrom<&'a DynSpatial<T>> for Spatial<T, ORDER>
+where
+    Order<ORDER>: SupportedOrder<T>,
+{
+    type Error = InsufficientOrder;
+
+    /// Convert the moments into their compile-time checked counterpart, dropping all moments of higher order.
+    fn try_from(moments: &'a DynSpatial<T>) -> Result<Self, Self::Error> {
+        InsufficientOrder::check(ORDER, moments.order())?;
+
+        let mut storage = <Order<ORDER> as SealedSupportedOrder<T>>::Storage::zeros();
+        copy_truncated(&moments.0, &mut storage);
+        Ok(Self(storage))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use approx::{assert_abs_diff_eq, assert_relative_eq};
+
+    use crate::{DynCentral, DynSpatial, InsufficientOrder, Moments, Spatial};
+
+    #[test]
+    fn test_empty() {
+        let points: [(f64, f64); 0] = [];
+        let moments = DynSpatial::<f64>::from_points(4, points.iter());
+        assert_eq!(moments.order(), 4);
+        assert_eq!(moments.get(2, 2), Some(0.0));
+    }
+
+    #[test]
+    fn test_get() {
+        let points = [(0.0, 0.0), (2.0, 0.0), (2.0, 3.0), (0.0, 3.0)];
+        let moments = DynSpatial::<f64>::from_points(5, points.iter());
+        assert_abs_diff_eq!(moments.get(0, 0).unwrap(), 6.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(
+            moments.get(4, 1).unwrap(),
+            32.0 * 9.0 / 10.0,
+            epsilon = 10e-9
+        );
+        assert_eq!(moments.get(3, 3), None);
+        assert_eq!(moments.get(usize::MAX, 1), None);
+        assert_eq!(moments.get(1, usize::MAX), None);
+    }
+
+    #[test]
+    fn test_high_order() {
+        // The binomial coefficients of order 70 exceed usize, the moments of [-1, 1]² are 4 / ((i + 1) * (j + 1))
+        let points = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)];
+        let moments = DynSpatial::<f64>::from_points(70, points.iter());
+        for (i, j) in [(0, 0), (70, 0), (36, 34), (20, 50)] {
+            assert_relative_eq!(
+                moments.get(i, j).unwrap(),
+                4.0 / ((i + 1) * (j + 1)) as f64,
+                max_relative = 10e-9
+            );
+        }
+        assert_abs_diff_eq!(moments.get(35, 35).unwrap(), 0.0, epsilon = 10e-9);
+
+        let central = DynCentral::from(&moments);
+        assert!(central.iter().all(|(_, _, value)| value.is_finite()));
+    }
+
+    #[test]
+    fn test_iter() {
+        // The clockwise rectangle [1, 2] x [1, 4] has the moments (2^(i+1) - 1) / (i+1) * (4^(j+1) - 1) / (j+1)
+        let points = [(1.0, 1.0), (1.0, 4.0), (2.0, 4.0), (2.0, 1.0)];
+        let moments = DynSpatial::<f64>::from_points(4, points.iter());
+        assert_eq!(moments.iter().len(), 15);
+        let mut indices = Vec::new();
+        for (i, j, value) in moments.iter() {
+            assert_eq!(moments.get(i, j), Some(value));
+            let (i1, j1) = (i as i32 + 1, j as i32 + 1);
+            let expected = (2f64.powi(i1) - 1.0) / i1 as f64 * (4f64.powi(j1) - 1.0) / j1 as f64;
+            assert_relative_eq!(value, expected, max_relative = 10e-12);
+            indices.push((i, j));
+        }
+        assert_eq!(
+            indices[..6],
+            [(0, 0), (1, 0), (2, 0), (3, 0), (4, 0), (0, 1)]
+        );
+        assert_eq!(
+            moments.to_vec(),
+            moments
+                .iter()
+                .map(|(_, _, value)| value)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_conversion() {
+        // A clockwise right triangle with legs of 3 and 4
+        let points = [(0.0, 0.0), (0.0, 4.0), (3.0, 0.0)];
+        let fixed: Spatial<f64, 4> = points.iter().collect();
+        let dynamic = DynSpatial::from_points(4, points.iter());
+        assert_eq!(DynSpatial::from(&fixed), dynamic);
+        assert_eq!(Spatial::<f64, 4>::try_from(&dynamic), Ok(fixed));
+
+        // Truncation keeps the lower orders, e.g. m11 = a²b² / 24
+        let truncated = Spatial::<f64, 2>::try_from(&dynamic).unwrap();
+        assert_abs_diff_eq!(truncated.get::<0, 0>(), 6.0);
+        assert_abs_diff_eq!(truncated.get::<1, 1>(), 6.0);
+        assert_abs_diff_eq!(truncated.get::<1, 1>(), dynamic.get(1, 1).unwrap());
+        assert_eq!(DynSpatial::from(&truncated).order(), 2);
+
+        assert_eq!(
+            Spatial::<f64, 5>::try_from(&dynamic),
+            Err(InsufficientOrder {
+                required: 5,
+                available: 4
+            })
+        );
+    }
+}
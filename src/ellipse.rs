@@ -0,0 +1,209 @@
+use std::convert::TryFrom;
+
+use crate::{
+    implementation::{principal_variances, Storage},
+    Central, Centroid, DynCentral, Index, InsufficientOrder, Order, Scalar, SupportedIndex,
+    SupportedOrder,
+};
+
+/// The ellipse with the same area-normalized second-order central moments as an image or contour.
+///
+/// The semi-axes are `2 * sqrt(λ / μ00)` for the eigenvalues `λ` of the matrix `[[μ20, μ11], [μ11, μ02]]`,
+/// i.e. they equal half of `major_axis_length` and `minor_axis_length` of scikit-image's `regionprops`.
+/// For a filled ellipse, they recover its actual semi-axes.
+/// For degenerate contours or images without any area, the ellipse is centered at the origin
+/// and both its axes and its orientation are 0 like the moments themselves.
+///
+/// The orientation `0.5 * atan2(2μ11, μ20 - μ02)` is the angle of the major axis in radians within (-π/2, π/2].
+/// It is measured from the positive x-axis towards the positive y-axis. As the y-axis points downwards in images,
+/// positive angles rotate clockwise on screen.
+///
+/// Other libraries report the same ellipse in different conventions:
+/// - `regionprops.orientation` of scikit-image is measured from the row axis (y) towards the column axis (x),
+///   i.e. it equals `π/2 - orientation` wrapped into [-π/2, π/2] by adding or subtracting π.
+/// - `cv::fitEllipse` of OpenCV fits a conic to the contour points by least squares instead of using moments,
+///   such that both only agree for elliptical contours. Its `RotatedRect` holds the full axes `2 * semi_major`
+///   and `2 * semi_minor` in `size` and the angle of `size.width` from the x-axis towards the y-axis in degrees.
+///   Thus, its angle equals `orientation` in degrees modulo 180 if `size.width` is the major axis,
+///   and `orientation` plus 90 degrees modulo 180 otherwise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ellipse<T: Scalar> {
+    /// The center of the ellipse, i.e. the centroid of the image or contour.
+    pub center: Centroid<T>,
+    /// The length of the semi-major axis.
+    pub semi_major: T,
+    /// The length of the semi-minor axis.
+    pub semi_minor: T,
+    /// The angle of the major axis towards the x-axis in radians.
+    pub orientation: T,
+}
+
+impl<T: Scalar> Ellipse<T> {
+    /// The area `π * a * b` of the ellipse.
+    #[inline(always)]
+    pub fn area(&self) -> T {
+        T::PI * self.semi_major * self.semi_minor
+    }
+
+    /// Sample `count` points evenly spaced in the parametric angle along the boundary, i.e. for drawing.
+    /// The first point is the end of the major axis in the direction of the orientation.
+    pub fn sample(&self, count: usize) -> Vec<(T, T)> {
+        let (sin, cos) = (self.orientation.sin(), self.orientation.cos());
+        let step = T::TWO * T::PI / T::from_usize(count.max(1));
+        (0..count)
+            .map(|index| {
+                let t = T::from_usize(index) * step;
+                let (u, v) = (self.semi_major * t.cos(), self.semi_minor * t.sin());
+                (
+                    self.center.x + u.mul_add(cos, -(v * sin)),
+                    self.center.y + u.mul_add(sin, v * cos),
+                )
+            })
+            .collect()
+    }
+
+    /// Calculate the ellipse from central moments of an order >= 2 around their centroid.
+    fn calculate<S: Storage<T>>(moments: &S, centroid: Option<Centroid<T>>) -> Self {
+        let center = centroid.unwrap_or(Centroid {
+            x: T::ZERO,
+            y: T::ZERO,
+        });
+        match principal_variances(
+            moments.at(0, 0),
+            moments.at(2, 0),
+            moments.at(1, 1),
+            moments.at(0, 2),
+        ) {
+            Some((major, minor, orientation)) => Ellipse {
+                center,
+                semi_major: T::TWO * major.sqrt(),
+                semi_minor: T::TWO * minor.sqrt(),
+                orientation,
+            },
+            None => Ellipse {
+                center,
+                semi_major: T::ZERO,
+                semi_minor: T::ZERO,
+                orientation: T::ZERO,
+            },
+        }
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> From<&'a Central<T, ORDER>> for Ellipse<T>
+where
+    Order<ORDER>: SupportedOrder<T>,
+    Index<2, 0>: SupportedIndex<ORDER>,
+{
+    fn from(moments: &'a Central<T, ORDER>) -> Self {
+        Self::calculate(&moments.0, moments.centroid())
+    }
+}
+
+impl<'a, T: Scalar> TryFrom<&'a DynCentral<T>> for Ellipse<T> {
+    type Error = InsufficientOrder;
+
+    /// Calculate the ellipse, which requires moments of at least the second order.
+    fn try_from(moments: &'a DynCentral<T>) -> Result<Self, Self::Error> {
+        InsufficientOrder::check(2, moments.order())?;
+        Ok(Self::calculate(&moments.0, moments.centroid()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{
+        Central, Centroid, DynCentral, DynSpatial, Ellipse, GrayImage, InsufficientOrder, Spatial,
+    };
+
+    fn ellipse(points: &[(f64, f64)]) -> Ellipse<f64> {
+        Ellipse::from(&Central::from(&points.iter().collect::<Spatial<f64, 2>>()))
+    }
+
+    #[test]
+    fn test_contour() {
+        let expected = Ellipse {
+            center: Centroid { x: 50.0, y: 40.0 },
+            semi_major: 30.0,
+            semi_minor: 10.0,
+            orientation: 0.5,
+        };
+        let actual = ellipse(&expected.sample(2000));
+        assert_abs_diff_eq!(actual.center.x, 50.0, epsilon = 10e-6);
+        assert_abs_diff_eq!(actual.center.y, 40.0, epsilon = 10e-6);
+        assert_abs_diff_eq!(actual.semi_major, 30.0, epsilon = 10e-3);
+        assert_abs_diff_eq!(actual.semi_minor, 10.0, epsilon = 10e-3);
+        assert_abs_diff_eq!(actual.orientation, 0.5, epsilon = 10e-6);
+        assert_abs_diff_eq!(actual.area(), 300.0 * std::f64::consts::PI, epsilon = 0.1);
+
+        // The sampled points lie on the ellipse
+        for (x, y) in expected.sample(16) {
+            let (dx, dy) = (x - 50.0, y - 40.0);
+            let u = dx * 0.5f64.cos() + dy * 0.5f64.sin();
+            let v = -dx * 0.5f64.sin() + dy * 0.5f64.cos();
+            assert_abs_diff_eq!(
+                (u / 30.0).powi(2) + (v / 10.0).powi(2),
+                1.0,
+                epsilon = 10e-9
+            );
+        }
+    }
+
+    #[test]
+    fn test_almost_circular() {
+        // A rectangle whose sides only differ by 1e-8 still has a well-defined orientation
+        let (sin, cos) = 0.5f64.sin_cos();
+        let points: Vec<_> = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+            .iter()
+            .map(|(x, y)| (x * (1.0 + 1e-8), *y))
+            .map(|(x, y)| (x * cos - y * sin, x * sin + y * cos))
+            .collect();
+        let actual = ellipse(&points);
+        assert!(actual.semi_major > actual.semi_minor);
+        assert_abs_diff_eq!(actual.orientation, 0.5, epsilon = 10e-6);
+    }
+
+    #[test]
+    fn test_image_coordinates() {
+        // A bar from the top left to the bottom right of the image points "down", i.e. has a positive angle
+        let size: usize = 64;
+        let pixels: Vec<bool> = (0..size * size)
+            .map(|index| (index % size).abs_diff(index / size) <= 2)
+            .collect();
+        let image = GrayImage::new(size, size, &pixels[..]).unwrap();
+        let moments = DynCentral::from(&DynSpatial::<f64>::from_image(2, &image));
+        let ellipse = Ellipse::try_from(&moments).unwrap();
+
+        assert_abs_diff_eq!(
+            ellipse.orientation,
+            std::f64::consts::FRAC_PI_4,
+            epsilon = 10e-9
+        );
+        assert_abs_diff_eq!(ellipse.center.x, 31.5, epsilon = 10e-9);
+        assert!(ellipse.semi_major > 10.0 * ellipse.semi_minor);
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let empty = ellipse(&[]);
+        assert_eq!(empty.center, Centroid { x: 0.0, y: 0.0 });
+        assert_eq!((empty.semi_major, empty.semi_minor), (0.0, 0.0));
+        assert_eq!(ellipse(&[(1.0, 1.0), (4.0, 4.0)]), empty);
+
+        let moments = DynCentral::from(&DynSpatial::from_points(
+            1,
+            [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)],
+        ));
+        assert_eq!(
+            Ellipse::try_from(&moments),
+            Err(InsufficientOrder {
+                required: 2,
+                available: 1
+            })
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt;
+
+/// The error returned if moments of a runtime order do not cover the requested compile-time order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InsufficientOrder {
+    /// The order which was requested.
+    pub required: usize,
+    /// The order which was available.
+    pub available: usize,
+}
+
+impl InsufficientOrder {
+    /// Check whether moments of the `available` order cover the `required` order.
+    pub(crate) fn check(required: usize, available: usize) -> Result<(), Self> {
+        match available >= required {
+            true => Ok(()),
+            false => Err(InsufficientOrder {
+                required,
+                available,
+            }),
+        }
+    }
+}
+
+impl fmt::Display for InsufficientOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "moments of order {} are required, but only order {} is available",
+            self.required, self.available
+        )
+    }
+}
+
+impl std::error::Error for InsufficientOrder {}
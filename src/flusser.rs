@@ -0,0 +1,181 @@
+use std::convert::TryFrom;
+
+use crate::{
+    implementation::{calculate_complex_moment, complex_mul, complex_powi, Storage},
+    Central, DynCentral, DynNormalizedCentral, Index, InsufficientOrder, NormalizedCentral, Order,
+    Scalar, SupportedIndex, SupportedOrder,
+};
+
+/// Flusser's complete and independent set of rotation invariants built from complex moments.
+///
+/// The invariants are `Φ(p, q) = c_pq * c_12^(p - q)` for `p >= q` and `2 <= p + q <= order`.
+/// They are ordered by ascending degree `p + q` first and ascending `p` second.
+/// For `p == q` and the base `(p, q) == (2, 1)` the invariant is real and contributes one value,
+/// all others contribute their real part followed by their imaginary part.
+/// Up to the third order, this yields `[Φ(1, 1), Re Φ(2, 0), Im Φ(2, 0), Φ(2, 1), Re Φ(3, 0), Im Φ(3, 0)]`.
+///
+/// Calculated from [`Central`] moments, the invariants are translation and rotation invariant.
+/// Calculated from [`NormalizedCentral`] moments, they are additionally scale invariant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Flusser<T: Scalar> {
+    order: usize,
+    invariants: Vec<T>,
+}
+
+impl<T: Scalar> Flusser<T> {
+    /// The order up to which the invariants were calculated.
+    #[inline(always)]
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// The invariants in the documented order, i.e. for using them as a feature vector.
+    #[inline(always)]
+    pub fn invariants(&self) -> &[T] {
+        &self.invariants
+    }
+
+    /// Calculate the invariants up to a specific order.
+    /// `moments` must contain the central moments of an order >= max(order, 3).
+    fn calculate<S: Storage<T>>(moments: &S, order: usize) -> Self {
+        let base = calculate_complex_moment(moments, 1, 2);
+        let mut invariants = Vec::new();
+        for degree in 2..=order {
+            for p in (degree + 1) / 2..=degree {
+                let q = degree - p;
+                let c_pq = calculate_complex_moment(moments, p, q);
+                let invariant = complex_mul(c_pq, complex_powi(base, p - q));
+                invariants.push(invariant.0);
+                if p != q && (p, q) != (2, 1) {
+                    invariants.push(invariant.1);
+                }
+            }
+        }
+
+        Flusser { order, invariants }
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> From<&'a Central<T, ORDER>> for Flusser<T>
+where
+    Order<ORDER>: SupportedOrder<T>,
+    Index<3, 0>: SupportedIndex<ORDER>,
+{
+    fn from(moments: &'a Central<T, ORDER>) -> Self {
+        Self::calculate(&moments.0, ORDER)
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> From<&'a NormalizedCentral<T, ORDER>> for Flusser<T>
+where
+    Order<ORDER>: SupportedOrder<T>,
+    Index<3, 0>: SupportedIndex<ORDER>,
+{
+    fn from(moments: &'a NormalizedCentral<T, ORDER>) -> Self {
+        Self::calculate(&moments.0, ORDER)
+    }
+}
+
+impl<'a, T: Scalar> TryFrom<&'a DynCentral<T>> for Flusser<T> {
+    type Error = InsufficientOrder;
+
+    /// Calculate the invariants, which requires moments of at least the third order.
+    fn try_from(moments: &'a DynCentral<T>) -> Result<Self, Self::Error> {
+        InsufficientOrder::check(3, moments.order())?;
+        Ok(Self::calculate(&moments.0, moments.order()))
+    }
+}
+
+impl<'a, T: Scalar> TryFrom<&'a DynNormalizedCentral<T>> for Flusser<T> {
+    type Error = InsufficientOrder;
+
+    /// Calculate the invariants, which requires moments of at least the third order.
+    fn try_from(moments: &'a DynNormalizedCentral<T>) -> Result<Self, Self::Error> {
+        InsufficientOrder::check(3, moments.order())?;
+        Ok(Self::calculate(&moments.0, moments.order()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use approx::{assert_abs_diff_eq, assert_relative_eq};
+
+    use crate::{Central, DynCentral, DynSpatial, Flusser, Hu, NormalizedCentral, Spatial};
+
+    const POINTS: [(f64, f64); 7] = [
+        (1.0, 2.0),
+        (7.0, 1.0),
+        (9.0, 6.0),
+        (6.0, 5.0),
+        (4.0, 9.0),
+        (3.0, 4.0),
+        (2.0, 5.0),
+    ];
+
+    fn flusser<const ORDER: usize, I: IntoIterator<Item = (f64, f64)>>(points: I) -> Flusser<f64>
+    where
+        crate::Order<ORDER>: crate::SupportedOrder<f64>,
+        crate::Index<3, 0>: crate::SupportedIndex<ORDER>,
+    {
+        let moments: Spatial<f64, ORDER> = points.into_iter().collect();
+        Flusser::from(&NormalizedCentral::from(&moments))
+    }
+
+    #[test]
+    fn test_count() {
+        assert_eq!(flusser::<3, _>(POINTS).invariants().len(), 6);
+        assert_eq!(flusser::<4, _>(POINTS).invariants().len(), 11);
+        assert_eq!(flusser::<6, _>(POINTS).invariants().len(), 24);
+        assert_eq!(flusser::<6, _>(POINTS).order(), 6);
+    }
+
+    #[test]
+    fn test_hu() {
+        // The invariants Φ(1, 1) and Φ(2, 1) equal the first and the fourth of Hu's invariants
+        let invariants = flusser::<3, _>(POINTS);
+        let hu: Hu<f64> = POINTS.iter().collect();
+        assert_abs_diff_eq!(
+            invariants.invariants()[0],
+            hu.invariants()[0],
+            epsilon = 10e-12
+        );
+        assert_abs_diff_eq!(
+            invariants.invariants()[3],
+            hu.invariants()[3],
+            epsilon = 10e-12
+        );
+    }
+
+    #[test]
+    fn test_invariance() {
+        let expected = flusser::<6, _>(POINTS);
+
+        // Rotate by -2.1 rad, scale by 0.5 and translate by (4, -3)
+        let (sin, cos) = (-2.1f64).sin_cos();
+        let actual = flusser::<6, _>(POINTS.iter().map(|(x, y)| {
+            (
+                0.5 * (cos * x - sin * y) + 4.0,
+                0.5 * (sin * x + cos * y) - 3.0,
+            )
+        }));
+
+        for (expected, actual) in expected.invariants().iter().zip(actual.invariants()) {
+            assert_relative_eq!(expected, actual, epsilon = 10e-15, max_relative = 10e-6);
+        }
+    }
+
+    #[test]
+    fn test_dynamic() {
+        let moments: Spatial<f64, 5> = POINTS.iter().collect();
+        let dynamic = DynCentral::from(&DynSpatial::from(&moments));
+        assert_eq!(
+            Flusser::try_from(&dynamic),
+            Ok(Flusser::from(&Central::from(&moments)))
+        );
+
+        let insufficient = DynCentral::from(&DynSpatial::from_points(2, POINTS));
+        assert!(Flusser::try_from(&insufficient).is_err());
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{
+    implementation::{fourier_mellin_radial, PolarStorage},
+    DiskMapping, Image, Scalar,
+};
+
+/// The orthogonal Fourier–Mellin moments `Φ_nm` of an image mapped onto the unit disk.
+///
+/// The moments are `Φ_nm = (n + 1) / π * ∫∫ Q_n(r) exp(-imθ) f(r, θ) r dr dθ` with the radial polynomials `Q_n`,
+/// which are orthogonal with respect to the weight `r` and have `n` zeros spread evenly over the whole disk.
+/// Thus, they describe small images better than the [`Zernike`](crate::Zernike) moments.
+/// The moments are defined for `0 <= n <= max_order` and `|m| <= max_repetition`, which are independent of each other.
+/// Moments of negative repetition are the complex conjugates of the positive ones.
+///
+/// The magnitudes `|Φ_nm|` are invariant under rotations of the image around the center of the disk.
+/// Combined with [`DiskMapping::Centroid`], they are additionally invariant under translation and scale.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrthogonalFourierMellin<T: Scalar>(pub(crate) PolarStorage<T>);
+
+impl<T: Scalar> OrthogonalFourierMellin<T> {
+    /// Calculate the moments of a grayscale or binary image up to a specific order and repetition.
+    /// The pixels are sampled at their centers and those outside the unit disk are ignored.
+    pub fn from_image<I: Image<T>>(
+        image: &I,
+        max_order: usize,
+        max_repetition: usize,
+        mapping: DiskMapping,
+    ) -> Self {
+        let disk = mapping.unit_disk(image);
+
+        let mut moments = PolarStorage::zeros(max_order, max_repetition);
+        let mut radial = vec![T::ZERO; max_order + 1];
+        disk.for_each_polar_pixel(image, max_repetition, |r, angular| {
+            fourier_mellin_radial(r, &mut radial);
+            for (m, angular) in angular.iter().enumerate() {
+                for (n, q) in radial.iter().enumerate() {
+                    let value = moments.at_mut(n, m);
+                    value.0 = q.mul_add(angular.0, value.0);
+                    value.1 = q.mul_add(angular.1, value.1);
+                }
+            }
+        });
+
+        let pixel_area = disk.pixel_area();
+        for n in 0..=max_order {
+            let scale = T::from_usize(n + 1) / T::PI * pixel_area;
+            for m in 0..=max_repetition {
+                let value = moments.at_mut(n, m);
+                value.0 *= scale;
+                value.1 *= scale;
+            }
+        }
+
+        OrthogonalFourierMellin(moments)
+    }
+
+    /// The maximal order n of the moments.
+    #[inline(always)]
+    pub fn max_order(&self) -> usize {
+        self.0.max_order()
+    }
+
+    /// The maximal absolute repetition |m| of the moments.
+    #[inline(always)]
+    pub fn max_repetition(&self) -> usize {
+        self.0.max_repetition()
+    }
+
+    /// Get the moment `Φ_nm` as real and imaginary part, if it was calculated.
+    #[inline(always)]
+    pub fn get(&self, n: usize, m: isize) -> Option<(T, T)> {
+        self.0.get(n, m)
+    }
+
+    /// Get the rotation invariant magnitude `|Φ_nm|`, if the moment was calculated.
+    pub fn magnitude(&self, n: usize, m: isize) -> Option<T> {
+        self.get(n, m)
+            .map(|(real, imaginary)| real.mul_add(real, imaginary * imaginary).sqrt())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use crate::{DiskMapping, GrayImage, OrthogonalFourierMellin};
+
+    const SIZE: usize = 128;
+
+    fn image<F: Fn(f64, f64) -> f64>(f: F) -> Vec<f64> {
+        (0..SIZE * SIZE)
+            .map(|index| {
+                let x = (index % SIZE) as f64 + 0.5 - SIZE as f64 / 2.0;
+                let y = (index / SIZE) as f64 + 0.5 - SIZE as f64 / 2.0;
+                f(x / (SIZE as f64 / 2.0), y / (SIZE as f64 / 2.0))
+            })
+            .collect()
+    }
+
+    fn fourier_mellin(pixels: &[f64], mapping: DiskMapping) -> OrthogonalFourierMellin<f64> {
+        let image = GrayImage::new(SIZE, SIZE, pixels).unwrap();
+        OrthogonalFourierMellin::from_image(&image, 5, 4, mapping)
+    }
+
+    #[test]
+    fn test_orthogonality() {
+        // An image equal to Q_2 = 10r² - 12r + 3 only yields Φ_20 = 1
+        let moments = fourier_mellin(
+            &image(|x, y| {
+                let r = (x * x + y * y).sqrt();
+                10.0 * r * r - 12.0 * r + 3.0
+            }),
+            DiskMapping::Inscribed,
+        );
+        assert_abs_diff_eq!(moments.get(2, 0).unwrap().0, 1.0, epsilon = 0.05);
+        for n in 0..=5 {
+            for m in -4..=4 {
+                if (n, m) != (2, 0) {
+                    assert_abs_diff_eq!(moments.magnitude(n, m).unwrap(), 0.0, epsilon = 0.05);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotation() {
+        // Rotating the pixels by 90 degrees keeps the magnitudes
+        let pixels = image(|x, y| (x + 0.3).max(0.0) * (1.0 - y).max(0.0));
+        let mut rotated = vec![0.0; SIZE * SIZE];
+        for y in 0..SIZE {
+            for x in 0..SIZE {
+                rotated[x * SIZE + (SIZE - 1 - y)] = pixels[y * SIZE + x];
+            }
+        }
+
+        for mapping in [
+            DiskMapping::Inscribed,
+            DiskMapping::Circumscribed,
+            DiskMapping::Centroid,
+        ] {
+            let expected = fourier_mellin(&pixels, mapping);
+            let actual = fourier_mellin(&rotated, mapping);
+            for n in 0..=5 {
+                for m in -4..=4 {
+                    assert_abs_diff_eq!(
+                        expected.magnitude(n, m).unwrap(),
+                        actual.magnitude(n, m).unwrap(),
+                        epsilon = 10e-9
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_scale() {
+        // The same shape at different positions and scales
+        let shape = |x: f64, y: f64| match x.abs() < 1.0 && y.abs() < 0.5 && x + y < 0.8 {
+            true => 1.0,
+            false => 0.0,
+        };
+        let pixels = image(|x, y| shape((x + 0.3) / 0.4, (y - 0.3) / 0.4));
+        let small = fourier_mellin(&pixels, DiskMapping::Centroid);
+        let large = fourier_mellin(
+            &image(|x, y| shape((x - 0.1) / 0.75, (y + 0.2) / 0.75)),
+            DiskMapping::Centroid,
+        );
+
+        for n in 0..=5 {
+            for m in 0..=4 {
+                assert_abs_diff_eq!(
+                    small.magnitude(n, m).unwrap(),
+                    large.magnitude(n, m).unwrap(),
+                    epsilon = 0.05
+                );
+            }
+        }
+
+        // Scaling the intensities keeps the disk and only scales the moments
+        let bright: Vec<_> = pixels.iter().map(|value| value * 255.0).collect();
+        let bright = fourier_mellin(&bright, DiskMapping::Centroid);
+        for n in 0..=5 {
+            for m in 0..=4 {
+                assert_abs_diff_eq!(
+                    bright.magnitude(n, m).unwrap(),
+                    255.0 * small.magnitude(n, m).unwrap(),
+                    epsilon = 10e-9
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_addressing() {
+        let image = GrayImage::new(2, 2, &[1.0f32, 0.0, 0.0, 1.0][..]).unwrap();
+        let moments =
+            OrthogonalFourierMellin::<f32>::from_image(&image, 2, 5, DiskMapping::Circumscribed);
+        assert_eq!(moments.max_order(), 2);
+        assert_eq!(moments.max_repetition(), 5);
+        assert!(moments.get(0, 5).is_some());
+        assert!(moments.get(2, -5).is_some());
+        assert!(moments.get(3, 0).is_none());
+        assert!(moments.get(1, 6).is_none());
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{
+    implementation::{
+        hermite_functions, tabulate, triangular_inverse, triangular_transform, DynStorage, Storage,
+    },
+    Image, Iter, Scalar,
+};
+
+/// The orthonormal Gaussian–Hermite moments `η_pq` of an image normalized to `[-1, 1]²` for `p + q <= order`.
+///
+/// The moments are `η_pq = ∫∫ Ĥ_p(x) Ĥ_q(y) f(x, y) dx dy` with the Gaussian–Hermite functions
+/// `Ĥ_n(x) = (2^n n! sqrt(π) σ)^(-1/2) H_n(x / σ) exp(-x² / (2σ²))` of scale `σ` and the Hermite polynomials `H_n`.
+/// The Gaussian weight smooths the image, such that the moments are robust against noise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GaussianHermite<T: Scalar> {
+    sigma: T,
+    moments: DynStorage<T>,
+}
+
+impl<T: Scalar> GaussianHermite<T> {
+    /// Calculate the moments of a grayscale or binary image up to a specific order with the scale `sigma`.
+    /// The pixels are sampled at their centers, i.e. pixel (x, y) maps to `((2x + 1) / width - 1, (2y + 1) / height - 1)`.
+    /// It is `None` unless `sigma` > 0, where the Gaussian–Hermite functions are defined.
+    pub fn from_image<I: Image<T>>(image: &I, order: usize, sigma: T) -> Option<Self> {
+        let valid = sigma > T::ZERO;
+        if !valid {
+            return None;
+        }
+
+        let (width, height) = (image.width(), image.height());
+        if width == 0 || height == 0 {
+            return Some(GaussianHermite {
+                sigma,
+                moments: DynStorage::zeros(order),
+            });
+        }
+
+        let columns = Self::tabulate(width, order, sigma);
+        let rows = Self::tabulate(height, order, sigma);
+        let mut moments = triangular_transform(image, &columns, &rows, order);
+
+        // The factor 1 / sigma normalizes the product of both Hermite functions
+        let scale = T::TWO / T::from_usize(width) * T::TWO / T::from_usize(height) / sigma;
+        for q in 0..=order {
+            for p in 0..=order - q {
+                *moments.at_mut(p, q) *= scale;
+            }
+        }
+
+        Some(GaussianHermite { sigma, moments })
+    }
+
+    /// The order up to which the moments were calculated.
+    #[inline(always)]
+    pub fn order(&self) -> usize {
+        self.moments.order()
+    }
+
+    /// The scale σ of the Gaussian–Hermite functions.
+    #[inline(always)]
+    pub fn sigma(&self) -> T {
+        self.sigma
+    }
+
+    /// Get the moment at a specific position, if it is covered by the order.
+    #[inline(always)]
+    pub fn get(&self, p: usize, q: usize) -> Option<T> {
+        match p <= self.moments.order() && q <= self.moments.order() - p {
+            true => Some(self.moments.at(p, q)),
+            false => None,
+        }
+    }
+
+    /// Iterate over all moments as `(p, q, value)` triples in the order documented at [`Iter`].
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.moments.as_slice(), self.moments.order())
+    }
+
+    /// Collect all moments into a vector ordered as documented at [`Iter`].
+    #[inline(always)]
+    pub fn to_vec(&self) -> Vec<T> {
+        self.moments.as_slice().to_vec()
+    }
+
+    /// Approximate the image from the moments as row-major pixels sampled at their centers.
+    /// The size of the output is arbitrary, such that it does not need to match the original image.
+    pub fn reconstruct(&self, width: usize, height: usize) -> Vec<T> {
+        let order = self.moments.order();
+        let columns = Self::tabulate(width, order, self.sigma);
+        let rows = Self::tabulate(height, order, self.sigma);
+        let mut pixels = triangular_inverse(&self.moments, &columns, &rows, (width, height));
+        pixels
+            .iter_mut()
+            .for_each(|pixel| *pixel = *pixel / self.sigma);
+        pixels
+    }
+
+    /// Evaluate the Hermite functions of the scaled coordinates for each pixel in a row or column.
+    fn tabulate(size: usize, order: usize, sigma: T) -> Vec<T> {
+        tabulate(size, order, |x, output| {
+            hermite_functions(x / sigma, output)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use crate::{GaussianHermite, GrayImage};
+
+    fn image<F: Fn(f64, f64) -> f64>(width: usize, height: usize, f: F) -> Vec<f64> {
+        (0..width * height)
+            .map(|index| {
+                let x = (2 * (index % width) + 1) as f64 / width as f64 - 1.0;
+                let y = (2 * (index / width) + 1) as f64 / height as f64 - 1.0;
+                f(x, y)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_basis_function() {
+        // An image equal to Ĥ_1(x) * Ĥ_2(y) only yields η_12 = 1, up to the truncation at the border
+        let sigma = 0.25;
+        let (width, height) = (120, 100);
+        let pixels = image(width, height, |x, y| {
+            let weight = (-(x * x + y * y) / (2.0 * sigma * sigma)).exp();
+            let (x, y) = (x / sigma, y / sigma);
+            2.0 * x * (4.0 * y * y - 2.0) * weight / (16.0 * std::f64::consts::PI).sqrt() / sigma
+        });
+        let image = GrayImage::new(width, height, &pixels).unwrap();
+        let moments = GaussianHermite::<f64>::from_image(&image, 5, sigma).unwrap();
+
+        assert_eq!(moments.order(), 5);
+        assert_eq!(moments.sigma(), sigma);
+        assert_eq!(moments.to_vec().len(), 21);
+        for (p, q, value) in moments.iter() {
+            let expected = match (p, q) {
+                (1, 2) => 1.0,
+                _ => 0.0,
+            };
+            assert_abs_diff_eq!(value, expected, epsilon = 10e-4);
+        }
+        assert!(moments.get(3, 3).is_none());
+        assert!(moments.get(usize::MAX, 1).is_none());
+    }
+
+    #[test]
+    fn test_high_order() {
+        let (width, height) = (128, 128);
+        let pixels = image(width, height, |x, y| {
+            match (x - 0.1).abs() < 0.4 && (y + 0.2).abs() < 0.3 {
+                true => 1.0,
+                false => 0.0,
+            }
+        });
+        let image = GrayImage::new(width, height, &pixels).unwrap();
+
+        let error = |order: usize| {
+            let moments = GaussianHermite::<f64>::from_image(&image, order, 0.3).unwrap();
+            assert!(moments.iter().all(|(_, _, value)| value.is_finite()));
+            moments
+                .reconstruct(width, height)
+                .iter()
+                .zip(&pixels)
+                .map(|(actual, expected)| (actual - expected).powi(2))
+                .sum::<f64>()
+        };
+        assert!(error(10) > error(20));
+        assert!(error(20) > error(40));
+    }
+
+    #[test]
+    fn test_invalid_sigma() {
+        let pixels = image(8, 8, |x, y| x * y);
+        let image = GrayImage::new(8, 8, &pixels).unwrap();
+        for sigma in [0.0, -0.3, f64::NAN] {
+            assert!(GaussianHermite::<f64>::from_image(&image, 2, sigma).is_none());
+        }
+    }
+}
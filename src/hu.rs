@@ -0,0 +1,187 @@
+use std::iter::FromIterator;
+
+use crate::{
+    implementation::Storage, Index, NormalizedCentral, Order, Point, Scalar, Spatial,
+    SupportedIndex, SupportedOrder,
+};
+
+/// Hu's seven moment invariants, which are invariant under translation, scale and rotation.
+/// The seventh invariant changes its sign under reflection.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hu<T: Scalar>([T; 7]);
+
+impl<T: Scalar> Hu<T> {
+    /// Restore the invariants from their values, i.e. for matching against previously stored shapes.
+    #[inline(always)]
+    pub fn from_invariants(invariants: [T; 7]) -> Self {
+        Hu(invariants)
+    }
+
+    /// The seven invariants in the order defined by Hu (and used by `cv::HuMoments`).
+    #[inline(always)]
+    pub fn invariants(&self) -> [T; 7] {
+        self.0
+    }
+
+    /// The invariants in their log-scaled form `-sign(h) * log10(|h|)`, which is commonly used for matching.
+    /// Invariants which are exactly zero stay zero.
+    pub fn log_scaled(&self) -> [T; 7] {
+        let mut result = [T::ZERO; 7];
+        for (output, h) in result.iter_mut().zip(self.0.iter()) {
+            if *h != T::ZERO {
+                *output = -T::ONE.copysign(*h) * h.abs().log10();
+            }
+        }
+        result
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> From<&'a NormalizedCentral<T, ORDER>> for Hu<T>
+where
+    Order<ORDER>: SupportedOrder<T>,
+    Index<3, 0>: SupportedIndex<ORDER>,
+{
+    fn from(moments: &'a NormalizedCentral<T, ORDER>) -> Self {
+        let nu = &moments.0;
+        let (n20, n11, n02) = (nu.at(2, 0), nu.at(1, 1), nu.at(0, 2));
+        let (n30, n21, n12, n03) = (nu.at(3, 0), nu.at(2, 1), nu.at(1, 2), nu.at(0, 3));
+
+        // Common sub-expressions
+        let t0 = n30 + n12;
+        let t1 = n21 + n03;
+        let q0 = t0 * t0;
+        let q1 = t1 * t1;
+        let n4 = T::TWO * T::TWO * n11;
+        let s = n20 + n02;
+        let d = n20 - n02;
+        let a = n30 - T::THREE * n12;
+        let b = T::THREE * n21 - n03;
+
+        Hu([
+            s,
+            d.mul_add(d, n4 * n11),
+            a.mul_add(a, b * b),
+            q0 + q1,
+            a * t0 * (q0 - T::THREE * q1) + b * t1 * (T::THREE * q0 - q1),
+            d.mul_add(q0 - q1, n4 * t0 * t1),
+            b * t0 * (q0 - T::THREE * q1) - a * t1 * (T::THREE * q0 - q1),
+        ])
+    }
+}
+
+impl<T: Scalar, P: Point<T>> FromIterator<P> for Hu<T> {
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        let moments: Spatial<T, 3> = iter.into_iter().collect();
+        Self::from(&NormalizedCentral::from(&moments))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Hu, NormalizedCentral, Spatial};
+
+    const POINTS: [(f64, f64); 7] = [
+        (1.0, 2.0),
+        (7.0, 1.0),
+        (9.0, 6.0),
+        (6.0, 5.0),
+        (4.0, 9.0),
+        (3.0, 4.0),
+        (2.0, 5.0),
+    ];
+
+    #[test]
+    fn test_definition() {
+        let hu: Hu<f64> = POINTS.iter().collect();
+        let nu = NormalizedCentral::from(&POINTS.iter().collect::<Spatial<f64, 3>>()).to_vec();
+        let (n20, n11, n02) = (nu[2], nu[5], nu[7]);
+        let (n30, n21, n12, n03) = (nu[3], nu[6], nu[8], nu[9]);
+
+        let h = hu.invariants();
+        assert_abs_diff_eq!(h[0], n20 + n02, epsilon = 10e-12);
+        assert_abs_diff_eq!(
+            h[1],
+            (n20 - n02).powi(2) + 4.0 * n11.powi(2),
+            epsilon = 10e-12
+        );
+        assert_abs_diff_eq!(
+            h[2],
+            (n30 - 3.0 * n12).powi(2) + (3.0 * n21 - n03).powi(2),
+            epsilon = 10e-12
+        );
+        assert_abs_diff_eq!(
+            h[3],
+            (n30 + n12).powi(2) + (n21 + n03).powi(2),
+            epsilon = 10e-12
+        );
+        assert_abs_diff_eq!(
+            h[4],
+            (n30 - 3.0 * n12) * (n30 + n12) * ((n30 + n12).powi(2) - 3.0 * (n21 + n03).powi(2))
+                + (3.0 * n21 - n03)
+                    * (n21 + n03)
+                    * (3.0 * (n30 + n12).powi(2) - (n21 + n03).powi(2)),
+            epsilon = 10e-12
+        );
+        assert_abs_diff_eq!(
+            h[5],
+            (n20 - n02) * ((n30 + n12).powi(2) - (n21 + n03).powi(2))
+                + 4.0 * n11 * (n30 + n12) * (n21 + n03),
+            epsilon = 10e-12
+        );
+        assert_abs_diff_eq!(
+            h[6],
+            (3.0 * n21 - n03) * (n30 + n12) * ((n30 + n12).powi(2) - 3.0 * (n21 + n03).powi(2))
+                - (n30 - 3.0 * n12)
+                    * (n21 + n03)
+                    * (3.0 * (n30 + n12).powi(2) - (n21 + n03).powi(2)),
+            epsilon = 10e-12
+        );
+    }
+
+    #[test]
+    fn test_invariance() {
+        let hu: Hu<f64> = POINTS.iter().collect();
+
+        // Rotate by 0.7 rad, scale by 2.5 and translate by (-3, 11)
+        let (sin, cos) = 0.7f64.sin_cos();
+        let transformed: Hu<f64> = POINTS
+            .iter()
+            .map(|(x, y)| {
+                (
+                    2.5 * (cos * x - sin * y) - 3.0,
+                    2.5 * (sin * x + cos * y) + 11.0,
+                )
+            })
+            .collect();
+
+        for (expected, actual) in hu.invariants().iter().zip(transformed.invariants().iter()) {
+            assert_abs_diff_eq!(expected, actual, epsilon = 10e-12);
+        }
+
+        // The seventh invariant flips its sign under reflection
+        let reflected: Hu<f64> = POINTS.iter().map(|(x, y)| (-x, *y)).collect();
+        assert_abs_diff_eq!(
+            hu.invariants()[0],
+            reflected.invariants()[0],
+            epsilon = 10e-12
+        );
+        assert_abs_diff_eq!(
+            hu.invariants()[6],
+            -reflected.invariants()[6],
+            epsilon = 10e-12
+        );
+    }
+
+    #[test]
+    fn test_log_scaled() {
+        let hu: Hu<f64> = POINTS.iter().collect();
+        for (h, log_scaled) in hu.invariants().iter().zip(hu.log_scaled().iter()) {
+            assert_abs_diff_eq!(-h.signum() * h.abs().log10(), log_scaled);
+        }
+
+        let hu = Hu::from_invariants([0.01, -0.001, 0.0, 1.0, 10.0, -100.0, 0.0]);
+        assert_eq!(hu.log_scaled(), [2.0, -3.0, 0.0, -0.0, -1.0, 2.0, 0.0]);
+    }
+}
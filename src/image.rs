@@ -0,0 +1,125 @@
+use crate::Image;
+
+/// A grayscale or binary image given as a row-major slice of pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GrayImage<'a, P> {
+    width: usize,
+    height: usize,
+    pixels: &'a [P],
+}
+
+impl<'a, P> GrayImage<'a, P> {
+    /// Wrap the row-major pixels of an image, if their number matches its size.
+    pub fn new(width: usize, height: usize, pixels: &'a [P]) -> Option<Self> {
+        match width.checked_mul(height) == Some(pixels.len()) {
+            true => Some(GrayImage {
+                width,
+                height,
+                pixels,
+            }),
+            false => None,
+        }
+    }
+
+    /// The row-major pixels of the image.
+    #[inline(always)]
+    pub fn pixels(&self) -> &'a [P] {
+        self.pixels
+    }
+}
+
+macro_rules! impl_image {
+    ( $scalar:ty ) => {
+        impl<'a> Image<$scalar> for GrayImage<'a, $scalar> {
+            #[inline(always)]
+            fn width(&self) -> usize {
+                self.width
+            }
+
+            #[inline(always)]
+            fn height(&self) -> usize {
+                self.height
+            }
+
+            #[inline(always)]
+            fn intensity(&self, x: usize, y: usize) -> $scalar {
+                self.pixels[y * self.width + x]
+            }
+        }
+
+        impl<'a> Image<$scalar> for GrayImage<'a, bool> {
+            #[inline(always)]
+            fn width(&self) -> usize {
+                self.width
+            }
+
+            #[inline(always)]
+            fn height(&self) -> usize {
+                self.height
+            }
+
+            #[inline(always)]
+            fn intensity(&self, x: usize, y: usize) -> $scalar {
+                match self.pixels[y * self.width + x] {
+                    true => 1.0,
+                    false => 0.0,
+                }
+            }
+        }
+
+        impl_image!(u8 as $scalar);
+        impl_image!(u16 as $scalar);
+        impl_image!(u32 as $scalar);
+    };
+    ( $old_type:ty as $new_type:ty ) => {
+        impl<'a> Image<$new_type> for GrayImage<'a, $old_type> {
+            #[inline(always)]
+            fn width(&self) -> usize {
+                self.width
+            }
+
+            #[inline(always)]
+            fn height(&self) -> usize {
+                self.height
+            }
+
+            #[inline(always)]
+            fn intensity(&self, x: usize, y: usize) -> $new_type {
+                self.pixels[y * self.width + x] as $new_type
+            }
+        }
+    };
+}
+
+impl_image!(f32);
+impl_image!(f64);
+impl_image!(f32 as f64);
+impl_image!(f64 as f32);
+
+#[cfg(test)]
+mod tests {
+    use crate::{GrayImage, Image};
+
+    #[test]
+    fn test_size() {
+        let pixels = [0u8; 6];
+        assert!(GrayImage::new(2, 3, &pixels).is_some());
+        assert!(GrayImage::new(3, 3, &pixels).is_none());
+        assert!(GrayImage::new(usize::MAX, 2, &pixels).is_none());
+    }
+
+    #[test]
+    fn test_intensity() {
+        let pixels = [0u8, 1, 2, 3, 4, 5];
+        let image = GrayImage::new(3, 2, &pixels).unwrap();
+        assert_eq!(Image::<f64>::width(&image), 3);
+        assert_eq!(Image::<f64>::height(&image), 2);
+        assert_eq!(Image::<f64>::intensity(&image, 2, 0), 2.0);
+        assert_eq!(Image::<f32>::intensity(&image, 0, 1), 3.0);
+
+        let pixels = [true, false];
+        let image = GrayImage::new(1, 2, &pixels).unwrap();
+        assert_eq!(Image::<f64>::intensity(&image, 0, 0), 1.0);
+        assert_eq!(Image::<f64>::intensity(&image, 0, 1), 0.0);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    implementation::{CentralMoments, NormalizedCentralMoments, Storage},
+    implementation::{binomial, CentralMoments, FixedStorage, NormalizedCentralMoments, Storage},
     Order, Point, Scalar,
 };
 
@@ -10,10 +10,17 @@ pub struct Accumulator<T: Scalar, S: Storage<T>> {
     pub current_point: (T, T),
 }
 
-impl<T: Scalar, S: Storage<T>> Accumulator<T, S> {
+impl<T: Scalar, S: FixedStorage<T>> Accumulator<T, S> {
     pub fn from_point<P: Point<T>>(point: P) -> Self {
+        Self::new(S::zeros(), point)
+    }
+}
+
+impl<T: Scalar, S: Storage<T>> Accumulator<T, S> {
+    /// Create an accumulator given a storage filled with zeros.
+    pub fn new<P: Point<T>>(storage: S, point: P) -> Self {
         Accumulator {
-            storage: S::zeros(),
+            storage,
             last_point: (point.x(), point.y()),
             current_point: (T::TWO, T::TWO),
         }
@@ -25,12 +32,54 @@ impl<T: Scalar, S: Storage<T>> Accumulator<T, S> {
         self.last_point = self.current_point;
     }
 
-    pub fn finalize<O: SealedSupportedOrder<T>>(mut self) -> S {
+    pub fn finalize<O: SealedSupportedOrder<T>>(self) -> S {
+        self.finalize_with(O::finalize)
+    }
+
+    /// Update the storage given a point, choosing the calculations based on the order of the storage at runtime.
+    pub fn update_dynamic<P: Point<T>>(&mut self, point: P) {
+        self.current_point = (point.x(), point.y());
+        match self.storage.order() {
+            0 => {
+                Order::<0>::update(self);
+            }
+            1 => {
+                Order::<1>::update(self);
+            }
+            2 => {
+                Order::<2>::update(self);
+            }
+            order => {
+                let dxy = Order::<3>::update(self);
+                for degree in 4..=order {
+                    update_degree(self, dxy, degree);
+                }
+            }
+        }
+        self.last_point = self.current_point;
+    }
+
+    /// Finalize the storage, choosing the calculations based on the order of the storage at runtime.
+    pub fn finalize_dynamic(self) -> S {
+        self.finalize_with(|acc, sign| match acc.storage.order() {
+            0 => Order::<0>::finalize(acc, sign),
+            1 => Order::<1>::finalize(acc, sign),
+            2 => Order::<2>::finalize(acc, sign),
+            order => {
+                Order::<3>::finalize(acc, sign);
+                for degree in 4..=order {
+                    finalize_degree(acc, sign, degree);
+                }
+            }
+        })
+    }
+
+    fn finalize_with<F: FnOnce(&mut Self, T)>(mut self, finalize: F) -> S {
         let first_moment = self.storage.get::<0, 0>();
         // Check whether there would be divisions (almost) by 0
         if first_moment.abs() > T::EPSILON {
             let sign = T::ONE.copysign(first_moment);
-            O::finalize(&mut self, sign);
+            finalize(&mut self, sign);
         }
         self.storage
     }
@@ -38,7 +87,7 @@ impl<T: Scalar, S: Storage<T>> Accumulator<T, S> {
 
 pub trait SealedSupportedOrder<T: Scalar>: CentralMoments<T> + NormalizedCentralMoments<T> {
     /// The underlying data storage.
-    type Storage: Storage<T>;
+    type Storage: FixedStorage<T>;
 
     /// A intermediate result of the computation which is useful in subsequent computations of higher orders.
     type IntermediateResult;
@@ -151,7 +200,7 @@ impl<T: Scalar> SealedSupportedOrder<T> for Order<2> {
 
 impl<T: Scalar> SealedSupportedOrder<T> for Order<3> {
     type Storage = [T; super::calculate_space::<3>()];
-    type IntermediateResult = ();
+    type IntermediateResult = T;
 
     fn update<S: Storage<T>>(acc: &mut Accumulator<T, S>) -> Self::IntermediateResult {
         let (dxy, last_plus_current, current_pow) = Order::<2>::update(acc);
@@ -186,6 +235,8 @@ impl<T: Scalar> SealedSupportedOrder<T> for Order<3> {
                 *a12,
             );
         }
+
+        dxy
     }
 
     fn finalize<S: Storage<T>>(acc: &mut Accumulator<T, S>, sign: T) {
@@ -199,15 +250,168 @@ impl<T: Scalar> SealedSupportedOrder<T> for Order<3> {
     }
 }
 
+/// Update all moments of a specific degree (I + J == degree) given the current state of the accumulator.
+/// In contrast to the hand-tuned lower orders, this is derived directly from Green's theorem and works for any degree.
+fn update_degree<T: Scalar, S: Storage<T>>(acc: &mut Accumulator<T, S>, dxy: T, degree: usize) {
+    let (x0, y0) = acc.last_point;
+    let (x1, y1) = acc.current_point;
+
+    for i in 0..=degree {
+        let j = degree - i;
+        let mut sum = T::ZERO;
+        for k in 0..=i {
+            let x = x0.powi(k as i32) * x1.powi((i - k) as i32);
+            for l in 0..=j {
+                let factor = binomial::<T>(k + l, l) * binomial(degree - k - l, j - l);
+                sum = (factor * x).mul_add(y0.powi(l as i32) * y1.powi((j - l) as i32), sum);
+            }
+        }
+
+        let value = acc.storage.at_mut(i, j);
+        *value = dxy.mul_add(sum, *value);
+    }
+}
+
+/// Finalize all moments of a specific degree (I + J == degree).
+fn finalize_degree<T: Scalar, S: Storage<T>>(acc: &mut Accumulator<T, S>, sign: T, degree: usize) {
+    let base = (degree + 2) * (degree + 1);
+    for i in 0..=degree {
+        let factor = T::ONE / (T::from_usize(base) * binomial(degree, i));
+        *acc.storage.at_mut(i, degree - i) *= factor.copysign(sign);
+    }
+}
+
+/// Implement the supported orders beyond the hand-tuned ones on top of the next lower order.
+macro_rules! impl_sealed_supported_order {
+    ( $order:literal => $lower_order:literal ) => {
+        impl<T: Scalar> SealedSupportedOrder<T> for Order<$order> {
+            type Storage = [T; super::calculate_space::<$order>()];
+            type IntermediateResult = T;
+
+            fn update<S: Storage<T>>(acc: &mut Accumulator<T, S>) -> Self::IntermediateResult {
+                let dxy = Order::<$lower_order>::update(acc);
+                update_degree(acc, dxy, $order);
+                dxy
+            }
+
+            fn finalize<S: Storage<T>>(acc: &mut Accumulator<T, S>, sign: T) {
+                Order::<$lower_order>::finalize(acc, sign);
+                finalize_degree(acc, sign, $order);
+            }
+        }
+    };
+}
+
+impl_sealed_supported_order!(4 => 3);
+impl_sealed_supported_order!(5 => 4);
+impl_sealed_supported_order!(6 => 5);
+impl_sealed_supported_order!(7 => 6);
+impl_sealed_supported_order!(8 => 7);
+impl_sealed_supported_order!(9 => 8);
+impl_sealed_supported_order!(10 => 9);
+
 #[cfg(test)]
 mod tests {
-    use approx::assert_abs_diff_eq;
+    use approx::{assert_abs_diff_eq, assert_relative_eq};
 
     use crate::{
-        implementation::{Accumulator, SealedSupportedOrder, Storage},
+        implementation::{Accumulator, DynStorage, SealedSupportedOrder, Storage},
         Order,
     };
 
+    use super::{finalize_degree, update_degree};
+
+    /// A clockwise, non-convex contour of the L-shape `[0, 1] x [0, 3] ∪ [1, 2] x [0, 1]`.
+    const L_SHAPE: [(f64, f64); 6] = [
+        (0.0, 0.0),
+        (0.0, 3.0),
+        (1.0, 3.0),
+        (1.0, 1.0),
+        (2.0, 1.0),
+        (2.0, 0.0),
+    ];
+
+    /// The exact moment `m_ij` of the L-shape as sum of the moments of both rectangles.
+    fn l_shape_moment(i: usize, j: usize) -> f64 {
+        let (i, j) = (i as i32 + 1, j as i32 + 1);
+        let rectangle = |(x0, x1): (f64, f64), (y0, y1): (f64, f64)| {
+            (x1.powi(i) - x0.powi(i)) / i as f64 * (y1.powi(j) - y0.powi(j)) / j as f64
+        };
+        rectangle((0.0, 1.0), (0.0, 3.0)) + rectangle((1.0, 2.0), (0.0, 1.0))
+    }
+
+    #[test]
+    fn test_generic_degree() {
+        type Storage10 = <Order<10> as SealedSupportedOrder<f64>>::Storage;
+
+        // The orders beyond the hand-tuned ones are calculated by the generic degree
+        let hand_tuned = {
+            let mut acc = Accumulator::<f64, Storage10>::from_point(L_SHAPE.last().unwrap());
+            for point in L_SHAPE.iter() {
+                acc.update::<Order<10>, _>(point);
+            }
+            acc.finalize::<Order<10>>()
+        };
+
+        // The generic degree also reproduces the hand-tuned ones, given the sign of the clockwise contour
+        let generic = {
+            let mut acc = Accumulator::<f64, Storage10>::from_point(L_SHAPE.last().unwrap());
+            for point in L_SHAPE.iter() {
+                acc.current_point = *point;
+                let dxy =
+                    acc.last_point.0 * acc.current_point.1 - acc.current_point.0 * acc.last_point.1;
+                for degree in 0..=10 {
+                    update_degree(&mut acc, dxy, degree);
+                }
+                acc.last_point = acc.current_point;
+            }
+            for degree in 0..=10 {
+                finalize_degree(&mut acc, -1.0, degree);
+            }
+            acc.storage
+        };
+
+        for j in 0..=10 {
+            for i in 0..=(10 - j) {
+                let expected = l_shape_moment(i, j);
+                assert_relative_eq!(hand_tuned.at(i, j), expected, max_relative = 10e-12);
+                assert_relative_eq!(generic.at(i, j), expected, max_relative = 10e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dynamic() {
+        type Storage10 = <Order<10> as SealedSupportedOrder<f64>>::Storage;
+
+        let fixed = {
+            let mut acc = Accumulator::<f64, Storage10>::from_point(L_SHAPE.last().unwrap());
+            for point in L_SHAPE.iter() {
+                acc.update::<Order<10>, _>(point);
+            }
+            acc.finalize::<Order<10>>()
+        };
+
+        // Runtime orders go beyond the largest supported fixed order
+        for order in 0..=14 {
+            let mut acc = Accumulator::new(DynStorage::zeros(order), L_SHAPE.last().unwrap());
+            for point in L_SHAPE.iter() {
+                acc.update_dynamic(point);
+            }
+            let dynamic = acc.finalize_dynamic();
+            assert_eq!(dynamic.order(), order);
+            if order <= 10 {
+                assert_eq!(dynamic, DynStorage::truncated(&fixed, order));
+            }
+            for j in 0..=order {
+                for i in 0..=(order - j) {
+                    let expected = l_shape_moment(i, j);
+                    assert_relative_eq!(dynamic.at(i, j), expected, max_relative = 10e-12);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_accumulator() {
         let points = [
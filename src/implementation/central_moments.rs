@@ -1,35 +1,42 @@
-use crate::{implementation::Storage, Order, Scalar};
+use crate::{
+    implementation::{binomial, Storage},
+    Centroid, Order, Scalar,
+};
 
 /// Implementation for calculating the central moments.
 pub trait CentralMoments<T: Scalar> {
-    type CentralIntermediateResult;
-
     /// Calculate the central moments inplace.
     /// `input` must contain the raw spatial moments of the appropriate order.
     /// `output` must be filled with zeros, again in appropriate order.
-    fn calculate_central_moments<S: Storage<T>>(
-        spatial: &S,
-        central: &mut S,
-    ) -> Self::CentralIntermediateResult;
+    /// `centroid` must be the result of `calculate_centroid` on `input`, or (0, 0) if it is not defined.
+    fn calculate_central_moments<S: Storage<T>>(spatial: &S, central: &mut S, centroid: (T, T));
+}
+
+/// Calculate the centroid given the raw spatial moments, which is `None` if it is not defined.
+pub fn calculate_centroid<T: Scalar, S: Storage<T>>(input: &S) -> Option<Centroid<T>> {
+    if input.order() < 1 {
+        return None;
+    }
+
+    let m00 = input.get::<0, 0>().abs();
+    match m00.abs() > T::EPSILON {
+        true => Some(Centroid {
+            x: input.get::<1, 0>() / m00,
+            y: input.get::<0, 1>() / m00,
+        }),
+        false => None,
+    }
 }
 
 impl<T: Scalar> CentralMoments<T> for Order<0> {
-    type CentralIntermediateResult = ();
-    fn calculate_central_moments<S: Storage<T>>(
-        input: &S,
-        output: &mut S,
-    ) -> Self::CentralIntermediateResult {
+    fn calculate_central_moments<S: Storage<T>>(input: &S, output: &mut S, _: (T, T)) {
         *output.get_mut::<0, 0>() = input.get::<0, 0>();
     }
 }
 
 impl<T: Scalar> CentralMoments<T> for Order<1> {
-    type CentralIntermediateResult = ();
-    fn calculate_central_moments<S: Storage<T>>(
-        input: &S,
-        output: &mut S,
-    ) -> Self::CentralIntermediateResult {
-        Order::<0>::calculate_central_moments(input, output);
+    fn calculate_central_moments<S: Storage<T>>(input: &S, output: &mut S, centroid: (T, T)) {
+        Order::<0>::calculate_central_moments(input, output, centroid);
         /*
          * Output is already zero
          * output.get_mut::<1, 0>() = T::ZERO;
@@ -39,37 +46,21 @@ impl<T: Scalar> CentralMoments<T> for Order<1> {
 }
 
 impl<T: Scalar> CentralMoments<T> for Order<2> {
-    type CentralIntermediateResult = (T, T);
-
-    fn calculate_central_moments<S: Storage<T>>(
-        input: &S,
-        output: &mut S,
-    ) -> Self::CentralIntermediateResult {
-        Order::<1>::calculate_central_moments(input, output);
-
-        let m00 = input.get::<0, 0>().abs();
-        let (cx, cy) = match m00.abs() > T::EPSILON {
-            true => (input.get::<1, 0>() / m00, input.get::<0, 1>() / m00),
-            false => (T::ZERO, T::ZERO),
-        };
+    fn calculate_central_moments<S: Storage<T>>(input: &S, output: &mut S, centroid: (T, T)) {
+        Order::<1>::calculate_central_moments(input, output, centroid);
 
+        let (cx, cy) = centroid;
         *output.get_mut::<2, 0>() = input.get::<2, 0>() - input.get::<1, 0>() * cx;
         *output.get_mut::<1, 1>() = input.get::<1, 1>() - input.get::<1, 0>() * cy;
         *output.get_mut::<0, 2>() = input.get::<0, 2>() - input.get::<0, 1>() * cy;
-
-        (cx, cy)
     }
 }
 
 impl<T: Scalar> CentralMoments<T> for Order<3> {
-    type CentralIntermediateResult = ();
-
-    fn calculate_central_moments<S: Storage<T>>(
-        input: &S,
-        output: &mut S,
-    ) -> Self::CentralIntermediateResult {
-        let (cx, cy) = Order::<2>::calculate_central_moments(input, output);
+    fn calculate_central_moments<S: Storage<T>>(input: &S, output: &mut S, centroid: (T, T)) {
+        Order::<2>::calculate_central_moments(input, output, centroid);
 
+        let (cx, cy) = centroid;
         *output.get_mut::<3, 0>() = input.get::<3, 0>()
             - cx * T::THREE.mul_add(output.get::<2, 0>(), cx * input.get::<1, 0>());
 
@@ -87,3 +78,69 @@ impl<T: Scalar> CentralMoments<T> for Order<3> {
             - cy * T::THREE.mul_add(output.get::<0, 2>(), cy * input.get::<0, 1>());
     }
 }
+
+/// Calculate all central moments of a specific degree (I + J == degree) by the binomial expansion of the spatial ones.
+fn calculate_central_degree<T: Scalar, S: Storage<T>>(
+    input: &S,
+    output: &mut S,
+    (cx, cy): (T, T),
+    degree: usize,
+) {
+    for i in 0..=degree {
+        let j = degree - i;
+        let mut sum = T::ZERO;
+        for k in 0..=i {
+            let x = binomial::<T>(i, k) * (-cx).powi((i - k) as i32);
+            for l in 0..=j {
+                let y = binomial::<T>(j, l) * (-cy).powi((j - l) as i32);
+                sum = (x * y).mul_add(input.at(k, l), sum);
+            }
+        }
+        *output.at_mut(i, j) = sum;
+    }
+}
+
+/// Calculate the central moments, choosing the calculations based on the order of the storage at runtime.
+/// `output` must be filled with zeros and be of the same order as `input`.
+/// `centroid` must be the result of `calculate_centroid` on `input`, or (0, 0) if it is not defined.
+pub fn calculate_central_moments_dynamic<T: Scalar, S: Storage<T>>(
+    input: &S,
+    output: &mut S,
+    centroid: (T, T),
+) {
+    match input.order() {
+        0 => Order::<0>::calculate_central_moments(input, output, centroid),
+        1 => Order::<1>::calculate_central_moments(input, output, centroid),
+        2 => Order::<2>::calculate_central_moments(input, output, centroid),
+        order => {
+            Order::<3>::calculate_central_moments(input, output, centroid);
+            for degree in 4..=order {
+                calculate_central_degree(input, output, centroid, degree);
+            }
+        }
+    }
+}
+
+/// Implement the supported orders beyond the hand-tuned ones on top of the next lower order.
+macro_rules! impl_central_moments {
+    ( $order:literal => $lower_order:literal ) => {
+        impl<T: Scalar> CentralMoments<T> for Order<$order> {
+            fn calculate_central_moments<S: Storage<T>>(
+                input: &S,
+                output: &mut S,
+                centroid: (T, T),
+            ) {
+                Order::<$lower_order>::calculate_central_moments(input, output, centroid);
+                calculate_central_degree(input, output, centroid, $order);
+            }
+        }
+    };
+}
+
+impl_central_moments!(4 => 3);
+impl_central_moments!(5 => 4);
+impl_central_moments!(6 => 5);
+impl_central_moments!(7 => 6);
+impl_central_moments!(8 => 7);
+impl_central_moments!(9 => 8);
+impl_central_moments!(10 => 9);
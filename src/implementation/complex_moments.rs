@@ -0,0 +1,66 @@
+use crate::{
+    implementation::{binomial, Storage},
+    Scalar,
+};
+
+/// Calculate the complex moment c_pq = ∫∫ (x + iy)^p (x - iy)^q f(x, y) dx dy as (real, imaginary) pair.
+/// `input` must contain the geometric moments of an order >= p + q.
+pub fn calculate_complex_moment<T: Scalar, S: Storage<T>>(input: &S, p: usize, q: usize) -> (T, T) {
+    let (mut real, mut imaginary) = (T::ZERO, T::ZERO);
+    for k in 0..=p {
+        for j in 0..=q {
+            // The coefficient is (-1)^(q - j) * i^(p - k + q - j)
+            let factor = binomial::<T>(p, k) * binomial(q, j);
+            let factor = match (q - j) % 2 == 0 {
+                true => factor,
+                false => -factor,
+            };
+
+            let value = factor * input.at(k + j, p + q - k - j);
+            match (p - k + q - j) % 4 {
+                0 => real += value,
+                1 => imaginary += value,
+                2 => real += -value,
+                _ => imaginary += -value,
+            }
+        }
+    }
+    (real, imaginary)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::calculate_complex_moment;
+    use crate::implementation::{DynStorage, Storage};
+
+    #[test]
+    fn test_low_orders() {
+        let (m20, m11, m02) = (2.0, 3.0, 5.0);
+        let (m30, m21, m12, m03) = (7.0, 11.0, 13.0, 17.0);
+
+        let mut moments = DynStorage::zeros(3);
+        *moments.at_mut(0, 0) = 1.0;
+        *moments.at_mut(2, 0) = m20;
+        *moments.at_mut(1, 1) = m11;
+        *moments.at_mut(0, 2) = m02;
+        *moments.at_mut(3, 0) = m30;
+        *moments.at_mut(2, 1) = m21;
+        *moments.at_mut(1, 2) = m12;
+        *moments.at_mut(0, 3) = m03;
+
+        assert_eq!(calculate_complex_moment(&moments, 0, 0), (1.0, 0.0));
+        assert_eq!(calculate_complex_moment(&moments, 1, 1), (m20 + m02, 0.0));
+        assert_eq!(
+            calculate_complex_moment(&moments, 2, 0),
+            (m20 - m02, 2.0 * m11)
+        );
+        let (real, imaginary) = calculate_complex_moment(&moments, 2, 1);
+        assert_abs_diff_eq!(real, m30 + m12);
+        assert_abs_diff_eq!(imaginary, m21 + m03);
+        let (real, imaginary) = calculate_complex_moment(&moments, 3, 0);
+        assert_abs_diff_eq!(real, m30 - 3.0 * m12);
+        assert_abs_diff_eq!(imaginary, 3.0 * m21 - m03);
+    }
+}
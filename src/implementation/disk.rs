@@ -0,0 +1,90 @@
+use crate::{implementation::complex_mul, Image, Point, Scalar};
+
+/// The mapping of pixel coordinates onto normalized coordinates in the unit disk.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitDisk<T: Scalar> {
+    pub center: (T, T),
+    pub radius: T,
+}
+
+impl<T: Scalar> UnitDisk<T> {
+    /// Map a point given in pixel coordinates onto normalized coordinates.
+    #[inline(always)]
+    pub fn map(&self, x: T, y: T) -> (T, T) {
+        (
+            (x - self.center.0) / self.radius,
+            (y - self.center.1) / self.radius,
+        )
+    }
+
+    /// Map a point of a contour onto normalized coordinates.
+    #[inline(always)]
+    pub fn map_point<P: Point<T>>(&self, point: P) -> MappedPoint<T> {
+        let (x, y) = self.map(point.x(), point.y());
+        MappedPoint(x, y)
+    }
+
+    /// The area of a single pixel in normalized coordinates.
+    #[inline(always)]
+    pub fn pixel_area(&self) -> T {
+        T::ONE / (self.radius * self.radius)
+    }
+
+    /// Call `f` with the radius and the conjugated angular parts `intensity * exp(-i * m * theta)` for 0 <= m <= max_repetition
+    /// of all non-zero pixels whose center lies inside the unit disk.
+    pub fn for_each_polar_pixel<I: Image<T>, F: FnMut(T, &[(T, T)])>(
+        &self,
+        image: &I,
+        max_repetition: usize,
+        mut f: F,
+    ) {
+        let mut angular = vec![(T::ZERO, T::ZERO); max_repetition + 1];
+        self.for_each_pixel(image, |u, v, intensity| {
+            let rho = u.mul_add(u, v * v).sqrt();
+            let direction = match rho > T::EPSILON {
+                true => (u / rho, -v / rho),
+                false => (T::ONE, T::ZERO),
+            };
+
+            let mut value = (intensity, T::ZERO);
+            for angular in angular.iter_mut() {
+                *angular = value;
+                value = complex_mul(value, direction);
+            }
+            f(rho, &angular);
+        });
+    }
+
+    /// Call `f` with the normalized coordinates and the intensity of all non-zero pixels whose center lies inside the unit disk.
+    pub fn for_each_pixel<I: Image<T>, F: FnMut(T, T, T)>(&self, image: &I, mut f: F) {
+        for y in 0..image.height() {
+            for x in 0..image.width() {
+                let intensity = image.intensity(x, y);
+                if intensity == T::ZERO {
+                    continue;
+                }
+
+                let (u, v) = self.map(T::from_usize(x) + T::F1_2, T::from_usize(y) + T::F1_2);
+                if u.mul_add(u, v * v) <= T::ONE {
+                    f(u, v, intensity);
+                }
+            }
+        }
+    }
+}
+
+/// A point of a contour in normalized coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MappedPoint<T: Scalar>(T, T);
+
+impl<T: Scalar> Point<T> for MappedPoint<T> {
+    #[inline(always)]
+    fn x(&self) -> T {
+        self.0
+    }
+
+    #[inline(always)]
+    fn y(&self) -> T {
+        self.1
+    }
+}
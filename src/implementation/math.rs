@@ -0,0 +1,255 @@
+use crate::{implementation::Storage, Point, Scalar};
+
+/// Calculate the binomial coefficient "n choose k" as scalar.
+/// As orders are chosen at runtime, the coefficients may exceed any integer type and are thus calculated in floating point.
+/// They are exact as long as they are representable, as every intermediate result is a binomial coefficient itself.
+pub fn binomial<T: Scalar>(n: usize, k: usize) -> T {
+    if k > n {
+        return T::ZERO;
+    }
+
+    // Exploit the symmetry to keep the number of steps small
+    let k = if k > n - k { n - k } else { k };
+    let mut result = T::ONE;
+    for i in 0..k {
+        // The product of (i + 1) consecutive numbers is always divisible by (i + 1)!
+        result = result * T::from_usize(n - i) / T::from_usize(i + 1);
+    }
+    result
+}
+
+/// Multiply two complex numbers given as (real, imaginary) pairs.
+#[inline(always)]
+pub fn complex_mul<T: Scalar>((a, b): (T, T), (c, d): (T, T)) -> (T, T) {
+    (a.mul_add(c, -(b * d)), a.mul_add(d, b * c))
+}
+
+/// Calculate the eigenvalues `(λ_max, λ_min)` of the symmetric matrix `[[a, b], [b, c]]`
+/// together with the angle of the eigenvector of `λ_max` towards the first axis within (-π/2, π/2].
+/// For multiples of the identity matrix, every direction is an eigenvector and the angle is 0.
+pub fn symmetric_eigen<T: Scalar>(a: T, b: T, c: T) -> (T, T, T) {
+    let mean = (a + c) * T::F1_2;
+    let half_difference = (a - c) * T::F1_2;
+
+    // Scale the components of the radius to avoid overflows and underflows while squaring them
+    let scale = match half_difference.abs() > b.abs() {
+        true => half_difference.abs(),
+        false => b.abs(),
+    };
+    if scale == T::ZERO {
+        return (mean, mean, T::ZERO);
+    }
+    let (x, y) = (half_difference / scale, b / scale);
+    let radius = scale * x.mul_add(x, y * y).sqrt();
+
+    // Derive the eigenvalue of smaller magnitude from the determinant to avoid cancellation
+    let larger = mean + radius.copysign(mean);
+    let smaller = a.mul_add(c, -(b * b)) / larger;
+    let (max, min) = match larger > smaller {
+        true => (larger, smaller),
+        false => (smaller, larger),
+    };
+    (max, min, (T::TWO * b).atan2(a - c) * T::F1_2)
+}
+
+/// Calculate the variances `(λ_max, λ_min)` along the principal axes together with the angle of the major axis,
+/// i.e. the eigen-decomposition of the covariance matrix `[[μ20, μ11], [μ11, μ02]] / μ00` of an image or contour.
+/// It is `None` for degenerate contours or images without any area.
+pub fn principal_variances<T: Scalar>(m00: T, m20: T, m11: T, m02: T) -> Option<(T, T, T)> {
+    if m00 <= T::EPSILON {
+        return None;
+    }
+
+    let (major, minor, angle) = symmetric_eigen(m20 / m00, m11 / m00, m02 / m00);
+
+    // Rounding may yield tiny negative values for degenerate shapes like lines
+    let variance = |value: T| match value > T::ZERO {
+        true => value,
+        false => T::ZERO,
+    };
+    Some((variance(major), variance(minor), angle))
+}
+
+/// Calculate the moment `μ'_pq` in the coordinate system rotated by an angle given as `(sin, cos)`,
+/// i.e. the moment of `u^p v^q` with `u = x cos + y sin` and `v = -x sin + y cos`.
+/// `input` must contain the moments of an order >= p + q.
+pub fn rotated_moment<T: Scalar, S: Storage<T>>(
+    input: &S,
+    p: usize,
+    q: usize,
+    (sin, cos): (T, T),
+) -> T {
+    let mut result = T::ZERO;
+    for k in 0..=p {
+        // The x^k y^(p - k) part of u^p
+        let u = binomial::<T>(p, k) * cos.powi(k as i32) * sin.powi((p - k) as i32);
+        for l in 0..=q {
+            // The x^l y^(q - l) part of v^q
+            let v = binomial::<T>(q, l) * (-sin).powi(l as i32) * cos.powi((q - l) as i32);
+            result = (u * v).mul_add(input.at(k + l, p + q - k - l), result);
+        }
+    }
+    result
+}
+
+/// Calculate the moment `μ'_pq / (μ00 σ^(p + q))` rotated like [`rotated_moment`] and standardized by the mass
+/// and the standard deviation `σ`, e.g. the skewness for `p + q = 3`. It is 0 if the deviation is (almost) 0.
+pub fn standardized_moment<T: Scalar, S: Storage<T>>(
+    input: &S,
+    p: usize,
+    q: usize,
+    rotation: (T, T),
+    deviation: T,
+) -> T {
+    match deviation > T::EPSILON {
+        true => {
+            rotated_moment(input, p, q, rotation) / input.at(0, 0) / deviation.powi((p + q) as i32)
+        }
+        false => T::ZERO,
+    }
+}
+
+/// Raise a complex number given as (real, imaginary) pair to a non-negative integer power.
+pub fn complex_powi<T: Scalar>(base: (T, T), exponent: usize) -> (T, T) {
+    let mut result = (T::ONE, T::ZERO);
+    for _ in 0..exponent {
+        result = complex_mul(result, base);
+    }
+    result
+}
+
+/// Calculate the minimal and maximal coordinates of some points, if there are any.
+pub fn bounding_box<T: Scalar, P: Point<T>>(points: &[P]) -> Option<((T, T), (T, T))> {
+    let first = points.first()?;
+    let mut min = (first.x(), first.y());
+    let mut max = min;
+    for point in points {
+        let (x, y) = (point.x(), point.y());
+        if x < min.0 {
+            min.0 = x;
+        } else if x > max.0 {
+            max.0 = x;
+        }
+        if y < min.1 {
+            min.1 = y;
+        } else if y > max.1 {
+            max.1 = y;
+        }
+    }
+    Some((min, max))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::{assert_abs_diff_eq, assert_relative_eq};
+
+    use super::{
+        binomial, complex_mul, complex_powi, principal_variances, rotated_moment, symmetric_eigen,
+    };
+    use crate::implementation::{DynStorage, Storage};
+
+    #[test]
+    fn test_complex() {
+        assert_eq!(complex_mul((1.0, 2.0), (3.0, -1.0)), (5.0, 5.0));
+        assert_eq!(complex_powi((0.0, 1.0), 0), (1.0, 0.0));
+        assert_eq!(complex_powi((0.0, 1.0), 2), (-1.0, 0.0));
+        assert_eq!(complex_powi((1.0, 1.0), 3), (-2.0, 2.0));
+    }
+
+    #[test]
+    fn test_symmetric_eigen() {
+        let (max, min, angle) = symmetric_eigen(3.0, 1.0, 3.0);
+        assert_abs_diff_eq!(max, 4.0, epsilon = 10e-12);
+        assert_abs_diff_eq!(min, 2.0, epsilon = 10e-12);
+        assert_abs_diff_eq!(angle, std::f64::consts::FRAC_PI_4, epsilon = 10e-12);
+
+        let (max, min, angle) = symmetric_eigen(1.0, 0.0, 5.0);
+        assert_eq!((max, min), (5.0, 1.0));
+        assert_abs_diff_eq!(angle, std::f64::consts::FRAC_PI_2, epsilon = 10e-12);
+
+        // A tiny eigenvalue does not suffer from cancellation
+        let (max, min, _) = symmetric_eigen(1e8, 1e4, 1.0 + 2f64.powi(-20));
+        assert_relative_eq!(max, 100000001.0, max_relative = 10e-15);
+        assert_relative_eq!(min, 9.536743068695069e-7, max_relative = 10e-15);
+
+        // Negative definite matrices keep the order of the eigenvalues
+        let (max, min, _) = symmetric_eigen(-3.0, 1.0, -3.0);
+        assert_abs_diff_eq!(max, -2.0, epsilon = 10e-12);
+        assert_abs_diff_eq!(min, -4.0, epsilon = 10e-12);
+
+        // Isotropic matrices have no preferred direction, while almost isotropic ones keep theirs
+        assert_eq!(symmetric_eigen(2.0, 0.0, 2.0), (2.0, 2.0, 0.0));
+        assert_eq!(symmetric_eigen(0.0, 0.0, 0.0), (0.0, 0.0, 0.0));
+        let (max, min, angle) = symmetric_eigen(2.0, 1e-12, 2.0);
+        assert!(max > min);
+        assert_abs_diff_eq!(angle, std::f64::consts::FRAC_PI_4, epsilon = 10e-12);
+    }
+
+    #[test]
+    fn test_principal_variances() {
+        let (major, minor, angle) = principal_variances(2.0, 6.0, 2.0, 6.0).unwrap();
+        assert_abs_diff_eq!(major, 4.0, epsilon = 10e-12);
+        assert_abs_diff_eq!(minor, 2.0, epsilon = 10e-12);
+        assert_abs_diff_eq!(angle, std::f64::consts::FRAC_PI_4, epsilon = 10e-12);
+
+        // Tiny negative variances of lines are clamped and shapes without mass have no variances
+        let (_, minor, _) = principal_variances(1.0, 1.0, 1.0, 1.0 - 1e-15).unwrap();
+        assert_eq!(minor, 0.0);
+        assert_eq!(principal_variances(0.0, 1.0, 0.0, 1.0), None);
+    }
+
+    #[test]
+    fn test_rotated_moment() {
+        let mut moments = DynStorage::zeros(2);
+        *moments.at_mut(2, 0) = 5.0;
+        *moments.at_mut(1, 1) = 2.0;
+        *moments.at_mut(0, 2) = 3.0;
+
+        // Rotating by 90 degrees swaps the axes and negates the mixed moment
+        let rotation = (1.0, 0.0);
+        assert_abs_diff_eq!(
+            rotated_moment(&moments, 2, 0, rotation),
+            3.0,
+            epsilon = 10e-12
+        );
+        assert_abs_diff_eq!(
+            rotated_moment(&moments, 1, 1, rotation),
+            -2.0,
+            epsilon = 10e-12
+        );
+        assert_abs_diff_eq!(
+            rotated_moment(&moments, 0, 2, rotation),
+            5.0,
+            epsilon = 10e-12
+        );
+
+        // Rotating onto the principal axes removes the mixed moment
+        let (_, _, angle) = symmetric_eigen(5.0, 2.0, 3.0);
+        let rotation = (f64::sin(angle), f64::cos(angle));
+        assert_abs_diff_eq!(
+            rotated_moment(&moments, 1, 1, rotation),
+            0.0,
+            epsilon = 10e-12
+        );
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(binomial::<f64>(0, 0), 1.0);
+        assert_eq!(binomial::<f64>(1, 0), 1.0);
+        assert_eq!(binomial::<f64>(1, 1), 1.0);
+        assert_eq!(binomial::<f64>(4, 2), 6.0);
+        assert_eq!(binomial::<f64>(10, 3), 120.0);
+        assert_eq!(binomial::<f32>(10, 7), 120.0);
+        assert_eq!(binomial::<f64>(20, 10), 184756.0);
+        assert_eq!(binomial::<f64>(3, 4), 0.0);
+
+        // Coefficients beyond the range of usize do not overflow
+        assert_eq!(binomial::<f64>(50, 25), 126410606437752.0);
+        assert_relative_eq!(
+            binomial::<f64>(140, 70),
+            9.382096969784004e40,
+            max_relative = 10e-14
+        );
+    }
+}
@@ -1,9 +1,39 @@
 mod accumulator;
 mod central_moments;
+mod complex_moments;
+mod disk;
+mod math;
 mod normalized_central_moments;
+mod polar;
+mod polynomials;
+mod radial;
+mod raster;
+mod separable;
 mod storage;
 
 pub use self::accumulator::{Accumulator, SealedSupportedOrder};
-pub use self::central_moments::CentralMoments;
-pub use self::normalized_central_moments::NormalizedCentralMoments;
-pub use self::storage::{calculate_space, Storage};
+pub use self::central_moments::{
+    calculate_central_moments_dynamic, calculate_centroid, CentralMoments,
+};
+pub use self::complex_moments::calculate_complex_moment;
+pub use self::disk::UnitDisk;
+pub use self::math::{
+    binomial, bounding_box, complex_mul, complex_powi, principal_variances, standardized_moment,
+    symmetric_eigen,
+};
+pub use self::normalized_central_moments::{
+    calculate_normalized_central_moments_dynamic, NormalizedCentralMoments,
+};
+pub use self::polar::PolarStorage;
+pub use self::polynomials::{
+    hermite_functions, krawtchouk_polynomials, legendre_coefficients, legendre_polynomials,
+    tchebichef_polynomials,
+};
+pub use self::radial::{
+    fourier_mellin_radial, pseudo_zernike_radial, zernike_radial, zernike_radial_coefficients,
+};
+pub use self::raster::accumulate_image;
+pub use self::separable::{
+    separable_inverse, separable_transform, tabulate, triangular_inverse, triangular_transform,
+};
+pub use self::storage::{calculate_space, copy_truncated, DynStorage, FixedStorage, Storage};
@@ -62,7 +62,7 @@ impl<T: Scalar> NormalizedCentralMoments<T> for Order<2> {
 }
 
 impl<T: Scalar> NormalizedCentralMoments<T> for Order<3> {
-    type NormalizedCentralIntermediateResult = ();
+    type NormalizedCentralIntermediateResult = T;
     fn calculate_normalized_central_moments<S: Storage<T>>(
         input: &S,
         output: &mut S,
@@ -75,5 +75,77 @@ impl<T: Scalar> NormalizedCentralMoments<T> for Order<3> {
         *output.get_mut::<2, 1>() = input.get::<2, 1>() * s3;
         *output.get_mut::<1, 2>() = input.get::<1, 2>() * s3;
         *output.get_mut::<0, 3>() = input.get::<0, 3>() * s3;
+
+        inv_m00
+    }
+}
+
+/// Calculate all normalized central moments of a specific degree (I + J == degree).
+fn calculate_normalized_central_degree<T: Scalar, S: Storage<T>>(
+    input: &S,
+    output: &mut S,
+    inv_m00: T,
+    degree: usize,
+) {
+    // The moments are scaled by m00^-(degree / 2 + 1)
+    let mut scale = inv_m00.powi((degree / 2 + 1) as i32);
+    if degree % 2 == 1 {
+        scale *= inv_m00.abs().sqrt();
+    }
+
+    for i in 0..=degree {
+        *output.at_mut(i, degree - i) = input.at(i, degree - i) * scale;
     }
 }
+
+/// Calculate the normalized central moments, choosing the calculations based on the order of the storage at runtime.
+/// `output` must be filled with zeros and be of the same order as `input`.
+pub fn calculate_normalized_central_moments_dynamic<T: Scalar, S: Storage<T>>(
+    input: &S,
+    output: &mut S,
+) {
+    match input.order() {
+        0 => {
+            Order::<0>::calculate_normalized_central_moments(input, output);
+        }
+        1 => {
+            Order::<1>::calculate_normalized_central_moments(input, output);
+        }
+        2 => {
+            Order::<2>::calculate_normalized_central_moments(input, output);
+        }
+        order => {
+            let inv_m00 = Order::<3>::calculate_normalized_central_moments(input, output);
+            for degree in 4..=order {
+                calculate_normalized_central_degree(input, output, inv_m00, degree);
+            }
+        }
+    }
+}
+
+/// Implement the supported orders beyond the hand-tuned ones on top of the next lower order.
+macro_rules! impl_normalized_central_moments {
+    ( $order:literal => $lower_order:literal ) => {
+        impl<T: Scalar> NormalizedCentralMoments<T> for Order<$order> {
+            type NormalizedCentralIntermediateResult = T;
+
+            fn calculate_normalized_central_moments<S: Storage<T>>(
+                input: &S,
+                output: &mut S,
+            ) -> Self::NormalizedCentralIntermediateResult {
+                let inv_m00 =
+                    Order::<$lower_order>::calculate_normalized_central_moments(input, output);
+                calculate_normalized_central_degree(input, output, inv_m00, $order);
+                inv_m00
+            }
+        }
+    };
+}
+
+impl_normalized_central_moments!(4 => 3);
+impl_normalized_central_moments!(5 => 4);
+impl_normalized_central_moments!(6 => 5);
+impl_normalized_central_moments!(7 => 6);
+impl_normalized_central_moments!(8 => 7);
+impl_normalized_central_moments!(9 => 8);
+impl_normalized_central_moments!(10 => 9);
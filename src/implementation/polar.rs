@@ -0,0 +1,59 @@
+use crate::Scalar;
+
+/// A heap-allocated storage of complex coefficients addressed by order n and non-negative repetition m.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolarStorage<T: Scalar> {
+    max_order: usize,
+    max_repetition: usize,
+    values: Vec<(T, T)>,
+}
+
+impl<T: Scalar> PolarStorage<T> {
+    /// Create an instance of this storage with all values set to 0.
+    pub fn zeros(max_order: usize, max_repetition: usize) -> Self {
+        PolarStorage {
+            max_order,
+            max_repetition,
+            values: vec![(T::ZERO, T::ZERO); (max_order + 1) * (max_repetition + 1)],
+        }
+    }
+
+    #[inline(always)]
+    pub fn max_order(&self) -> usize {
+        self.max_order
+    }
+
+    #[inline(always)]
+    pub fn max_repetition(&self) -> usize {
+        self.max_repetition
+    }
+
+    /// Access the element of a specific order and repetition.
+    /// n must be <= self.max_order() and m must be <= self.max_repetition()
+    #[inline(always)]
+    pub fn at(&self, n: usize, m: usize) -> (T, T) {
+        self.values[n * (self.max_repetition + 1) + m]
+    }
+
+    /// Access the element of a specific order and repetition mutably.
+    /// n must be <= self.max_order() and m must be <= self.max_repetition()
+    #[inline(always)]
+    pub fn at_mut(&mut self, n: usize, m: usize) -> &mut (T, T) {
+        &mut self.values[n * (self.max_repetition + 1) + m]
+    }
+
+    /// Access the element of a specific order and a possibly negative repetition.
+    /// Coefficients of negative repetition are the complex conjugates of the positive ones.
+    pub fn get(&self, n: usize, m: isize) -> Option<(T, T)> {
+        let repetition = m.unsigned_abs();
+        if n > self.max_order || repetition > self.max_repetition {
+            return None;
+        }
+
+        let (real, imaginary) = self.at(n, repetition);
+        match m < 0 {
+            true => Some((real, -imaginary)),
+            false => Some((real, imaginary)),
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use crate::Scalar;
+
+/// Evaluate the Legendre polynomials P_0(x), ..., P_n(x) into `output`, whose length defines n.
+///
+/// This uses Bonnet's recursion (n + 1) * P_(n+1)(x) = (2n + 1) * x * P_n(x) - n * P_(n-1)(x).
+pub fn legendre_polynomials<T: Scalar>(x: T, output: &mut [T]) {
+    for n in 0..output.len() {
+        output[n] = match n {
+            0 => T::ONE,
+            1 => x,
+            n => {
+                let (lower, upper) = (T::from_usize(n - 1), T::from_usize(2 * n - 1));
+                (upper * x).mul_add(output[n - 1], -lower * output[n - 2]) / T::from_usize(n)
+            }
+        };
+    }
+}
+
+/// Calculate the coefficients of the Legendre polynomials P_0, ..., P_order in the monomial basis.
+/// The coefficient of x^k in P_n is stored at index n * (order + 1) + k.
+pub fn legendre_coefficients<T: Scalar>(order: usize) -> Vec<T> {
+    let stride = order + 1;
+    let mut output = vec![T::ZERO; stride * stride];
+    for n in 0..=order {
+        match n {
+            0 => output[0] = T::ONE,
+            1 => output[stride + 1] = T::ONE,
+            n => {
+                let (lower, upper) = (T::from_usize(n - 1), T::from_usize(2 * n - 1));
+                let divisor = T::from_usize(n);
+                for k in 0..=n {
+                    let shifted = match k {
+                        0 => T::ZERO,
+                        k => upper * output[(n - 1) * stride + k - 1],
+                    };
+                    output[n * stride + k] =
+                        (-lower).mul_add(output[(n - 2) * stride + k], shifted) / divisor;
+                }
+            }
+        }
+    }
+    output
+}
+
+/// Evaluate the orthonormal Hermite functions psi_0(t), ..., psi_n(t) into `output`, whose length defines n.
+///
+/// The functions psi_n(t) = (2^n n! sqrt(π))^(-1/2) H_n(t) exp(-t² / 2) include the Gaussian weight and the normalization.
+/// Unlike the Hermite polynomials H_n themselves, they stay bounded, such that their recurrence
+/// psi_(n+1) = sqrt(2 / (n + 1)) t psi_n - sqrt(n / (n + 1)) psi_(n-1) is stable for high orders.
+pub fn hermite_functions<T: Scalar>(t: T, output: &mut [T]) {
+    for n in 0..output.len() {
+        output[n] = match n {
+            0 => (-t * t * T::F1_2).exp() / T::PI.sqrt().sqrt(),
+            n => {
+                let lower = match n {
+                    1 => T::ZERO,
+                    n => (T::from_usize(n - 1) / T::from_usize(n)).sqrt() * output[n - 2],
+                };
+                ((T::TWO / T::from_usize(n)).sqrt() * t).mul_add(output[n - 1], -lower)
+            }
+        };
+    }
+}
+
+/// Evaluate the orthonormal discrete Tchebichef polynomials t_0, ..., t_order on the grid 0, ..., size - 1.
+/// The value of t_n(x) is stored at index n * size + x, order must be < size.
+///
+/// This uses the recurrence in x by Mukundan, which stays stable for high orders in contrast to the one in n.
+/// Only the first half is calculated explicitly, the second follows by the symmetry t_n(size - 1 - x) = (-1)^n t_n(x).
+pub fn tchebichef_polynomials<T: Scalar>(size: usize, order: usize) -> Vec<T> {
+    let mut output = vec![T::ZERO; size * (order + 1)];
+    if size == 0 {
+        return output;
+    }
+
+    let half = (size + 1) / 2;
+    let (n_size, n_size_1) = (T::from_usize(size), T::from_usize(size + 1));
+    for n in 0..=order {
+        let row = n * size;
+        output[row] = match n {
+            0 => T::ONE / n_size.sqrt(),
+            n => {
+                let factor = (T::from_usize(size - n) / T::from_usize(size + n)
+                    * T::from_usize(2 * n + 1)
+                    / T::from_usize(2 * n - 1))
+                .sqrt();
+                -factor * output[row - size]
+            }
+        };
+
+        let n_n1 = T::from_usize(n * (n + 1));
+        if half > 1 {
+            output[row + 1] = (T::ONE - n_n1 / T::from_usize(size - 1)) * output[row];
+        }
+        for x in 2..half {
+            let n_x = T::from_usize(x);
+            let divisor = n_x * T::from_usize(size - x);
+            let gamma_1 = (T::from_usize(2 * x - 1) * (n_size_1 - n_x) - n_x - n_n1) / divisor;
+            let gamma_2 = -(T::from_usize(x - 1) * (n_size_1 - n_x)) / divisor;
+            output[row + x] = gamma_1.mul_add(output[row + x - 1], gamma_2 * output[row + x - 2]);
+        }
+
+        for x in half..size {
+            let mirrored = output[row + size - 1 - x];
+            output[row + x] = match n % 2 {
+                0 => mirrored,
+                _ => -mirrored,
+            };
+        }
+    }
+    output
+}
+
+/// Evaluate the weighted Krawtchouk polynomials K_0, ..., K_order with parameter 0 < p < 1 on the grid 0, ..., size - 1.
+/// The value of K_n(x) is stored at index n * size + x, order must be < size.
+///
+/// The polynomials are weighted by sqrt(w(x) / rho(n)), which makes them orthonormal. For every x, the three-term recurrence in n
+/// is only stable while the values do not decay. Hence, it runs forward from n = 0 and backward from n = N = size - 1 towards
+/// n* = 2pqN - (q - p)(pN - x) with q = 1 - p, around which the values peak. Both starting values are known in closed form:
+/// K_0(x) = sqrt(w(x)) and K_N(x) = (-1)^x * sqrt(binomial(N, x) * q^x * p^(N - x)).
+/// To support large images, they are calculated in logarithmic space and the recurrences rescale their state where required.
+pub fn krawtchouk_polynomials<T: Scalar>(size: usize, order: usize, p: T) -> Vec<T> {
+    let mut output = vec![T::ZERO; size * (order + 1)];
+    if size == 0 {
+        return output;
+    }
+
+    let n_max = size - 1;
+    let q = T::ONE - p;
+    let (ln_p, ln_q) = (p.ln(), q.ln());
+    let pq = p * q;
+    let recurrence = |n: usize, x: T| {
+        (
+            p.mul_add(T::from_usize(n_max - n), T::from_usize(n) * q) - x,
+            (pq * T::from_usize(n * (n_max + 1 - n))).sqrt(),
+            (pq * T::from_usize((n + 1) * (n_max - n))).sqrt(),
+        )
+    };
+
+    let mut ln_binomial = T::ZERO;
+    for x in 0..size {
+        if x > 0 {
+            ln_binomial += (T::from_usize(n_max + 1 - x) / T::from_usize(x)).ln();
+        }
+        let (n_n, n_x) = (T::from_usize(n_max), T::from_usize(x));
+        let peak = T::TWO * pq * n_n - (q - p) * p.mul_add(n_n, -n_x);
+        let split = (0..n_max)
+            .find(|n| T::from_usize(*n) + T::F1_2 >= peak)
+            .unwrap_or(n_max);
+
+        // Forward: d_n K_(n+1) = a_n K_n - b_n K_(n-1)
+        let mut ln_scale =
+            (n_x.mul_add(ln_p, T::from_usize(n_max - x) * ln_q) + ln_binomial) * T::F1_2;
+        let (mut lower, mut current) = (T::ZERO, T::ONE);
+        for n in 0..=split.min(order) {
+            output[n * size + x] = current * ln_scale.exp();
+            if n < split.min(order) {
+                let (a, b, d) = recurrence(n, n_x);
+                let next = a.mul_add(current, -b * lower) / d;
+                (lower, current) = rescale(current, next, &mut ln_scale);
+            }
+        }
+
+        // Backward: b_n K_(n-1) = a_n K_n - d_n K_(n+1)
+        if order > split {
+            let mut ln_scale =
+                (n_x.mul_add(ln_q, T::from_usize(n_max - x) * ln_p) + ln_binomial) * T::F1_2;
+            let (mut upper, mut current) = (
+                T::ZERO,
+                match x % 2 {
+                    0 => T::ONE,
+                    _ => -T::ONE,
+                },
+            );
+            for n in (split + 1..=n_max).rev() {
+                if n <= order {
+                    output[n * size + x] = current * ln_scale.exp();
+                }
+                let (a, b, d) = recurrence(n, n_x);
+                let next = a.mul_add(current, -d * upper) / b;
+                (upper, current) = rescale(current, next, &mut ln_scale);
+            }
+        }
+    }
+    output
+}
+
+/// Rescale the state of a recurrence into a sane range, keeping track of the scale in logarithmic space.
+#[inline(always)]
+fn rescale<T: Scalar>(current: T, next: T, ln_scale: &mut T) -> (T, T) {
+    let magnitude = next.abs();
+    match magnitude > T::ONE {
+        true => {
+            *ln_scale += magnitude.ln();
+            (current / magnitude, next / magnitude)
+        }
+        false => (current, next),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::{
+        hermite_functions, krawtchouk_polynomials, legendre_coefficients, legendre_polynomials,
+        tchebichef_polynomials,
+    };
+
+    #[test]
+    fn test_legendre() {
+        let mut output = [0.0; 5];
+        for x in [-1.0f64, -0.4, 0.0, 0.3, 1.0] {
+            legendre_polynomials(x, &mut output);
+            assert_abs_diff_eq!(output[0], 1.0);
+            assert_abs_diff_eq!(output[1], x);
+            assert_abs_diff_eq!(output[2], (3.0 * x * x - 1.0) / 2.0, epsilon = 10e-12);
+            assert_abs_diff_eq!(
+                output[3],
+                (5.0 * x.powi(3) - 3.0 * x) / 2.0,
+                epsilon = 10e-12
+            );
+            assert_abs_diff_eq!(
+                output[4],
+                (35.0 * x.powi(4) - 30.0 * x * x + 3.0) / 8.0,
+                epsilon = 10e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_legendre_coefficients() {
+        let coefficients = legendre_coefficients::<f64>(6);
+        let mut output = [0.0; 7];
+        for x in [-1.0f64, -0.4, 0.0, 0.3, 1.0] {
+            legendre_polynomials(x, &mut output);
+            for (n, expected) in output.iter().enumerate() {
+                let actual: f64 = (0..=n)
+                    .map(|k| coefficients[n * 7 + k] * x.powi(k as i32))
+                    .sum();
+                assert_abs_diff_eq!(actual, expected, epsilon = 10e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_tchebichef() {
+        for size in [1, 2, 3, 8, 9, 200] {
+            let polynomials = tchebichef_polynomials::<f64>(size, size - 1);
+            for a in 0..size {
+                for b in a..size {
+                    let product: f64 = (0..size)
+                        .map(|x| polynomials[a * size + x] * polynomials[b * size + x])
+                        .sum();
+                    let expected = if a == b { 1.0 } else { 0.0 };
+                    assert_abs_diff_eq!(product, expected, epsilon = 10e-12);
+                }
+            }
+        }
+
+        // t_1 is linear and t_2 is quadratic
+        let polynomials = tchebichef_polynomials::<f64>(5, 2);
+        assert_abs_diff_eq!(polynomials[5 + 2], 0.0, epsilon = 10e-15);
+        assert_abs_diff_eq!(
+            polynomials[5 + 1] - polynomials[5],
+            polynomials[5 + 4] - polynomials[5 + 3],
+            epsilon = 10e-15
+        );
+        assert_abs_diff_eq!(polynomials[10 + 1], polynomials[10 + 3], epsilon = 10e-15);
+    }
+
+    #[test]
+    fn test_krawtchouk() {
+        let cases = [
+            (1, 0.5),
+            (2, 0.5),
+            (9, 0.3),
+            (64, 0.5),
+            (1024, 0.5),
+            (1024, 0.2),
+            (1024, 0.05),
+            (1024, 0.9),
+        ];
+        for (size, p) in cases {
+            let polynomials = krawtchouk_polynomials::<f64>(size, size - 1, p);
+            for a in (0..size).step_by(1 + size / 64) {
+                for b in (a..size).step_by(1 + size / 64) {
+                    let product: f64 = (0..size)
+                        .map(|x| polynomials[a * size + x] * polynomials[b * size + x])
+                        .sum();
+                    let expected = if a == b { 1.0 } else { 0.0 };
+                    assert_abs_diff_eq!(product, expected, epsilon = 10e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_hermite() {
+        let mut output = [0.0; 4];
+        for t in [-2.0f64, -0.4, 0.0, 0.3, 1.5] {
+            hermite_functions(t, &mut output);
+            let weight = (-t * t / 2.0).exp() / std::f64::consts::PI.sqrt().sqrt();
+            assert_abs_diff_eq!(output[0], weight, epsilon = 10e-15);
+            assert_abs_diff_eq!(output[1], 2.0 * t * weight / 2f64.sqrt(), epsilon = 10e-15);
+            assert_abs_diff_eq!(
+                output[2],
+                (4.0 * t * t - 2.0) * weight / 8f64.sqrt(),
+                epsilon = 10e-15
+            );
+            assert_abs_diff_eq!(
+                output[3],
+                (8.0 * t.powi(3) - 12.0 * t) * weight / 48f64.sqrt(),
+                epsilon = 10e-15
+            );
+        }
+
+        // The functions stay orthonormal for high orders
+        let (step, order) = (0.005, 40);
+        let mut values = vec![0.0; 9601 * (order + 1)];
+        for (i, output) in values.chunks_exact_mut(order + 1).enumerate() {
+            hermite_functions(i as f64 * step - 24.0, output);
+        }
+        for a in 0..=order {
+            for b in a..=order {
+                let product: f64 = values
+                    .chunks_exact(order + 1)
+                    .map(|values| values[a] * values[b] * step)
+                    .sum();
+                let expected = if a == b { 1.0 } else { 0.0 };
+                assert_abs_diff_eq!(product, expected, epsilon = 10e-9);
+            }
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use crate::Scalar;
+
+/// Evaluate the radial Zernike polynomials R_nm(rho) for all 0 <= m <= n <= max_order with even n - m.
+/// The values are stored at `output[n * (max_order + 1) + m]`; other entries are left untouched.
+///
+/// Instead of the explicit sum of factorials, this uses the recurrence
+/// R_nm = rho * (R_(n-1)|m-1| + R_(n-1)(m+1)) - R_(n-2)m with R_nn = rho^n, which is stable for high orders.
+pub fn zernike_radial<T: Scalar>(rho: T, max_order: usize, output: &mut [T]) {
+    let stride = max_order + 1;
+    let mut rho_n = T::ONE;
+    for n in 0..=max_order {
+        output[n * stride + n] = rho_n;
+        rho_n *= rho;
+
+        for m in (n % 2..n).step_by(2) {
+            let lower = output[(n - 1) * stride + m.abs_diff(1)] + output[(n - 1) * stride + m + 1];
+            output[n * stride + m] = rho.mul_add(lower, -output[(n - 2) * stride + m]);
+        }
+    }
+}
+
+/// Calculate the coefficients of the radial Zernike polynomial R_nm, where the value at index s belongs to rho^(n - 2s).
+/// n - m must be even.
+pub fn zernike_radial_coefficients<T: Scalar>(n: usize, m: usize) -> Vec<T> {
+    let (lower, upper) = ((n - m) / 2, (n + m) / 2);
+
+    // The first coefficient is "n choose (n - m) / 2", the others follow by their ratios
+    let mut coefficient = T::ONE;
+    for i in 1..=lower {
+        coefficient = coefficient * T::from_usize(upper + i) / T::from_usize(i);
+    }
+
+    let mut coefficients = Vec::with_capacity(lower + 1);
+    for s in 0..=lower {
+        coefficients.push(coefficient);
+        if s < lower {
+            coefficient = -coefficient * T::from_usize((upper - s) * (lower - s))
+                / T::from_usize((s + 1) * (n - s));
+        }
+    }
+    coefficients
+}
+
+/// Evaluate the radial pseudo-Zernike polynomials R_nm(rho) for all 0 <= m <= n <= max_order.
+/// The values are stored at `output[n * (max_order + 1) + m]`, `buffer` must hold (2 * max_order + 2)^2 values.
+///
+/// This exploits R_nm(rho) = R'_(2n+1)(2m+1)(sqrt(rho)) / sqrt(rho) with the radial Zernike polynomials R'
+/// to inherit the stability of their recurrence, as the explicit sum suffers from cancellation already for moderate orders.
+pub fn pseudo_zernike_radial<T: Scalar>(
+    rho: T,
+    max_order: usize,
+    buffer: &mut [T],
+    output: &mut [T],
+) {
+    let stride = max_order + 1;
+    if rho <= T::EPSILON {
+        // Only the constant term (-1)^n * (n + 1) of R_n0 remains
+        for n in 0..=max_order {
+            let constant = T::from_usize(n + 1);
+            output[n * stride] = match n % 2 {
+                0 => constant,
+                _ => -constant,
+            };
+            for m in 1..=n {
+                output[n * stride + m] = T::ZERO;
+            }
+        }
+        return;
+    }
+
+    let root = rho.sqrt();
+    let zernike_order = 2 * max_order + 1;
+    zernike_radial(root, zernike_order, buffer);
+    for n in 0..=max_order {
+        for m in 0..=n {
+            output[n * stride + m] = buffer[(2 * n + 1) * (zernike_order + 1) + 2 * m + 1] / root;
+        }
+    }
+}
+
+/// Evaluate the radial polynomials Q_0(r), ..., Q_n(r) of the orthogonal Fourier-Mellin moments into `output`, whose length defines n.
+///
+/// The polynomials Q_n(r) = Σ_s (-1)^(n+s) (n+s+1)! / ((n-s)! s! (s+1)!) r^s are the shifted Jacobi polynomials P_n^(0,1)(2r - 1).
+/// Hence, their three-term recurrence (n+1)(2n-1) Q_n = ((4n²-1)(2r-1) - 1) Q_(n-1) - (n-1)(2n+1) Q_(n-2) avoids
+/// the cancellation of the explicit sum.
+pub fn fourier_mellin_radial<T: Scalar>(r: T, output: &mut [T]) {
+    let x = T::TWO.mul_add(r, -T::ONE);
+    for n in 0..output.len() {
+        output[n] = match n {
+            0 => T::ONE,
+            1 => T::THREE.mul_add(r, -T::TWO),
+            n => {
+                let a = T::from_usize(4 * n * n - 1).mul_add(x, -T::ONE);
+                let b = T::from_usize((n - 1) * (2 * n + 1));
+                a.mul_add(output[n - 1], -b * output[n - 2]) / T::from_usize((n + 1) * (2 * n - 1))
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::{
+        fourier_mellin_radial, pseudo_zernike_radial, zernike_radial, zernike_radial_coefficients,
+    };
+
+    #[test]
+    fn test_zernike_radial() {
+        let mut output = [0.0; 25];
+        for rho in [0.0f64, 0.25, 0.5, 0.9, 1.0] {
+            zernike_radial(rho, 4, &mut output);
+            assert_abs_diff_eq!(output[0], 1.0);
+            assert_abs_diff_eq!(output[5 + 1], rho);
+            assert_abs_diff_eq!(output[10], 2.0 * rho * rho - 1.0, epsilon = 10e-12);
+            assert_abs_diff_eq!(output[12], rho * rho, epsilon = 10e-12);
+            assert_abs_diff_eq!(
+                output[15 + 1],
+                3.0 * rho.powi(3) - 2.0 * rho,
+                epsilon = 10e-12
+            );
+            assert_abs_diff_eq!(
+                output[20],
+                6.0 * rho.powi(4) - 6.0 * rho * rho + 1.0,
+                epsilon = 10e-12
+            );
+            assert_abs_diff_eq!(
+                output[20 + 2],
+                4.0 * rho.powi(4) - 3.0 * rho * rho,
+                epsilon = 10e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_boundary() {
+        // R_nm(1) = 1 for all valid n and m
+        let mut output = vec![0.0; 41 * 41];
+        zernike_radial(1.0, 40, &mut output);
+        for n in 0..=40 {
+            for m in (n % 2..=n).step_by(2) {
+                assert_abs_diff_eq!(output[n * 41 + m], 1.0, epsilon = 10e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_coefficients() {
+        let mut output = vec![0.0; 13 * 13];
+        for rho in [0.0f64, 0.3, 0.7, 1.0] {
+            zernike_radial(rho, 12, &mut output);
+            for n in 0..=12 {
+                for m in (n % 2..=n).step_by(2) {
+                    let expected: f64 = zernike_radial_coefficients::<f64>(n, m)
+                        .into_iter()
+                        .enumerate()
+                        .map(|(s, coefficient)| coefficient * rho.powi((n - 2 * s) as i32))
+                        .sum();
+                    assert_abs_diff_eq!(output[n * 13 + m], expected, epsilon = 10e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pseudo_zernike_radial() {
+        let mut buffer = vec![0.0; 8 * 8];
+        let mut output = [0.0; 16];
+        for rho in [0.0f64, 0.25, 0.5, 0.9, 1.0] {
+            pseudo_zernike_radial(rho, 3, &mut buffer, &mut output);
+            assert_abs_diff_eq!(output[0], 1.0, epsilon = 10e-12);
+            assert_abs_diff_eq!(output[4], 3.0 * rho - 2.0, epsilon = 10e-12);
+            assert_abs_diff_eq!(output[4 + 1], rho, epsilon = 10e-12);
+            assert_abs_diff_eq!(
+                output[8],
+                10.0 * rho * rho - 12.0 * rho + 3.0,
+                epsilon = 10e-12
+            );
+            assert_abs_diff_eq!(output[8 + 1], 5.0 * rho * rho - 4.0 * rho, epsilon = 10e-12);
+            assert_abs_diff_eq!(output[8 + 2], rho * rho, epsilon = 10e-12);
+            assert_abs_diff_eq!(
+                output[12],
+                35.0 * rho.powi(3) - 60.0 * rho * rho + 30.0 * rho - 4.0,
+                epsilon = 10e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_fourier_mellin_radial() {
+        let mut output = [0.0; 4];
+        for r in [0.0f64, 0.25, 0.5, 0.9, 1.0] {
+            fourier_mellin_radial(r, &mut output);
+            assert_abs_diff_eq!(output[0], 1.0);
+            assert_abs_diff_eq!(output[1], 3.0 * r - 2.0, epsilon = 10e-12);
+            assert_abs_diff_eq!(output[2], 10.0 * r * r - 12.0 * r + 3.0, epsilon = 10e-12);
+            assert_abs_diff_eq!(
+                output[3],
+                35.0 * r.powi(3) - 60.0 * r * r + 30.0 * r - 4.0,
+                epsilon = 10e-12
+            );
+        }
+    }
+}
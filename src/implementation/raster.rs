@@ -0,0 +1,57 @@
+use crate::{implementation::Storage, Image, Scalar};
+
+/// Accumulate the geometric moments m_ij = Σ_x Σ_y x^i y^j f(x, y) of an image into a storage filled with zeros.
+/// Like OpenCV, the pixels are located at their integer coordinates.
+pub fn accumulate_image<T: Scalar, S: Storage<T>, I: Image<T>>(image: &I, output: &mut S) {
+    let order = output.order();
+    let mut row = vec![T::ZERO; order + 1];
+    for y in 0..image.height() {
+        // Sum up the powers of x within the row first, as they only need to be weighted by y^j afterwards
+        row.iter_mut().for_each(|value| *value = T::ZERO);
+        for x in 0..image.width() {
+            let intensity = image.intensity(x, y);
+            if intensity == T::ZERO {
+                continue;
+            }
+
+            let (x, mut power) = (T::from_usize(x), intensity);
+            for value in row.iter_mut() {
+                *value += power;
+                power *= x;
+            }
+        }
+
+        let (y, mut power) = (T::from_usize(y), T::ONE);
+        for j in 0..=order {
+            for (i, value) in row.iter().take(order - j + 1).enumerate() {
+                *output.at_mut(i, j) = value.mul_add(power, output.at(i, j));
+            }
+            power *= y;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::accumulate_image;
+    use crate::{
+        implementation::{DynStorage, Storage},
+        GrayImage,
+    };
+
+    #[test]
+    fn test_small_image() {
+        // Pixels at (1, 0), (0, 1) and (2, 1) with the intensities 1, 2 and 3
+        let pixels = [0.0, 1.0, 0.0, 2.0, 0.0, 3.0];
+        let image = GrayImage::new(3, 2, &pixels[..]).unwrap();
+        let mut moments = DynStorage::<f64>::zeros(2);
+        accumulate_image(&image, &mut moments);
+
+        assert_eq!(moments.at(0, 0), 6.0);
+        assert_eq!(moments.at(1, 0), 1.0 + 6.0);
+        assert_eq!(moments.at(0, 1), 2.0 + 3.0);
+        assert_eq!(moments.at(2, 0), 1.0 + 12.0);
+        assert_eq!(moments.at(1, 1), 6.0);
+        assert_eq!(moments.at(0, 2), 2.0 + 3.0);
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{
+    implementation::{DynStorage, Storage},
+    Image, Scalar,
+};
+
+/// Calculate the moments `Σ_x Σ_y a_p(x) b_q(y) f(x, y)` for discrete polynomials tabulated on the pixel grid.
+/// `columns` must contain a_p(x) at index p * width + x for p <= order_x, `rows` b_q(y) at q * height + y for q <= order_y.
+/// The moment of (p, q) is stored at index q * (order_x + 1) + p.
+pub fn separable_transform<T: Scalar, I: Image<T>>(
+    image: &I,
+    columns: &[T],
+    rows: &[T],
+    order_x: usize,
+    order_y: usize,
+) -> Vec<T> {
+    let (width, height) = (image.width(), image.height());
+    let mut moments = vec![T::ZERO; (order_x + 1) * (order_y + 1)];
+    let mut row_sums = vec![T::ZERO; order_x + 1];
+    for y in 0..height {
+        // The moments are separable, such that the sums over each row are calculated first
+        row_sums.iter_mut().for_each(|sum| *sum = T::ZERO);
+        for x in 0..width {
+            let intensity = image.intensity(x, y);
+            if intensity == T::ZERO {
+                continue;
+            }
+
+            for (p, sum) in row_sums.iter_mut().enumerate() {
+                *sum = intensity.mul_add(columns[p * width + x], *sum);
+            }
+        }
+
+        for (q, moments) in moments.chunks_exact_mut(order_x + 1).enumerate() {
+            let polynomial = rows[q * height + y];
+            for (moment, sum) in moments.iter_mut().zip(&row_sums) {
+                *moment = sum.mul_add(polynomial, *moment);
+            }
+        }
+    }
+    moments
+}
+
+/// Restore the row-major pixels `f(x, y) = Σ_p Σ_q M_pq a_p(x) b_q(y)` from moments calculated by `separable_transform`.
+pub fn separable_inverse<T: Scalar>(
+    moments: &[T],
+    columns: &[T],
+    rows: &[T],
+    (width, height): (usize, usize),
+    order_x: usize,
+) -> Vec<T> {
+    let mut pixels = Vec::with_capacity(width * height);
+    let mut row_coefficients = vec![T::ZERO; order_x + 1];
+    for y in 0..height {
+        // Collapse the moments for this row into coefficients of the polynomials in x
+        row_coefficients.iter_mut().for_each(|sum| *sum = T::ZERO);
+        for (q, moments) in moments.chunks_exact(order_x + 1).enumerate() {
+            let polynomial = rows[q * height + y];
+            for (coefficient, moment) in row_coefficients.iter_mut().zip(moments) {
+                *coefficient = moment.mul_add(polynomial, *coefficient);
+            }
+        }
+
+        for x in 0..width {
+            pixels.push(
+                row_coefficients
+                    .iter()
+                    .enumerate()
+                    .fold(T::ZERO, |sum, (p, coefficient)| {
+                        coefficient.mul_add(columns[p * width + x], sum)
+                    }),
+            );
+        }
+    }
+    pixels
+}
+
+/// Calculate the moments `Σ_x Σ_y a_p(x) b_q(y) f(x, y)` for p + q <= order in the triangular layout of the geometric moments.
+/// `columns` and `rows` must be laid out as for `separable_transform` with order_x = order_y = order.
+pub fn triangular_transform<T: Scalar, I: Image<T>>(
+    image: &I,
+    columns: &[T],
+    rows: &[T],
+    order: usize,
+) -> DynStorage<T> {
+    let (width, height) = (image.width(), image.height());
+    let mut moments = DynStorage::zeros(order);
+    let mut row_sums = vec![T::ZERO; order + 1];
+    for y in 0..height {
+        // As in `separable_transform`, the sums over each row are calculated first
+        row_sums.iter_mut().for_each(|sum| *sum = T::ZERO);
+        for x in 0..width {
+            let intensity = image.intensity(x, y);
+            if intensity == T::ZERO {
+                continue;
+            }
+
+            for (p, sum) in row_sums.iter_mut().enumerate() {
+                *sum = intensity.mul_add(columns[p * width + x], *sum);
+            }
+        }
+
+        for q in 0..=order {
+            let polynomial = rows[q * height + y];
+            for (p, sum) in row_sums.iter().take(order - q + 1).enumerate() {
+                let moment = moments.at_mut(p, q);
+                *moment = sum.mul_add(polynomial, *moment);
+            }
+        }
+    }
+    moments
+}
+
+/// Restore the row-major pixels `f(x, y) = Σ_(p + q <= order) M_pq a_p(x) b_q(y)` from moments calculated by `triangular_transform`.
+pub fn triangular_inverse<T: Scalar, S: Storage<T>>(
+    moments: &S,
+    columns: &[T],
+    rows: &[T],
+    (width, height): (usize, usize),
+) -> Vec<T> {
+    let order = moments.order();
+    let mut pixels = Vec::with_capacity(width * height);
+    let mut row_coefficients = vec![T::ZERO; order + 1];
+    for y in 0..height {
+        for (p, coefficient) in row_coefficients.iter_mut().enumerate() {
+            *coefficient = (0..=order - p).fold(T::ZERO, |sum, q| {
+                moments.at(p, q).mul_add(rows[q * height + y], sum)
+            });
+        }
+
+        for x in 0..width {
+            pixels.push(
+                row_coefficients
+                    .iter()
+                    .enumerate()
+                    .fold(T::ZERO, |sum, (p, coefficient)| {
+                        coefficient.mul_add(columns[p * width + x], sum)
+                    }),
+            );
+        }
+    }
+    pixels
+}
+
+/// Evaluate functions of the normalized coordinates of the pixel centers in a row or column of a specific size.
+/// Pixel i maps to (2i + 1) / size - 1 in [-1, 1], the value of function n is stored at index n * size + i.
+/// `evaluate` is called with the coordinate and a buffer for the values of the functions 0..=order.
+pub fn tabulate<T: Scalar, F: FnMut(T, &mut [T])>(
+    size: usize,
+    order: usize,
+    mut evaluate: F,
+) -> Vec<T> {
+    let mut output = vec![T::ZERO; size * (order + 1)];
+    let mut buffer = vec![T::ZERO; order + 1];
+    for index in 0..size {
+        evaluate(
+            T::from_usize(2 * index + 1) / T::from_usize(size) - T::ONE,
+            &mut buffer,
+        );
+        for (n, value) in buffer.iter().enumerate() {
+            output[n * size + index] = *value;
+        }
+    }
+    output
+}
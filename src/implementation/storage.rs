@@ -2,6 +2,31 @@ use crate::Scalar;
 
 /// An efficient storage to access the required coefficients.
 pub trait Storage<T: Scalar>: std::fmt::Debug + Clone + PartialEq {
+    /// The "order" of the storage.
+    fn order(&self) -> usize;
+
+    /// Access the element at a specific 0-based position.
+    /// I + J must be <= self.order()
+    fn get<const I: usize, const J: usize>(&self) -> T;
+
+    /// Access the element at a specific 0-based position mutably.
+    /// I + J must be <= self.order()
+    fn get_mut<const I: usize, const J: usize>(&mut self) -> &mut T;
+
+    /// Access the element at a 0-based position only known at runtime.
+    /// i + j must be <= self.order()
+    fn at(&self, i: usize, j: usize) -> T;
+
+    /// Access the element at a 0-based position only known at runtime mutably.
+    /// i + j must be <= self.order()
+    fn at_mut(&mut self, i: usize, j: usize) -> &mut T;
+
+    /// Access all elements in their triangular layout.
+    fn as_slice(&self) -> &[T];
+}
+
+/// A storage whose order is known at compile time.
+pub trait FixedStorage<T: Scalar>: Storage<T> + Copy {
     /// The "order" of the storage.
     const ORDER: usize;
 
@@ -10,14 +35,74 @@ pub trait Storage<T: Scalar>: std::fmt::Debug + Clone + PartialEq {
 
     /// Create an instance of this storage with all values set to 0.
     fn zeros() -> Self;
+}
 
-    /// Access the element at a specific 0-based position.
-    /// I + J must be <= Self::ORDER
-    fn get<const I: usize, const J: usize>(&self) -> T;
+/// A heap-allocated storage whose order is only known at runtime.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynStorage<T: Scalar> {
+    order: usize,
+    data: Vec<T>,
+}
 
-    /// Access the element at a specific 0-based position mutably.
-    /// I + J must be <= Self::ORDER
-    fn get_mut<const I: usize, const J: usize>(&mut self) -> &mut T;
+impl<T: Scalar> DynStorage<T> {
+    /// Create an instance of this storage of a specific order with all values set to 0.
+    pub fn zeros(order: usize) -> Self {
+        DynStorage {
+            order,
+            data: vec![T::ZERO; (order + 1) * (order + 2) / 2],
+        }
+    }
+
+    /// Create an instance of this storage from the elements of another one, truncated to a specific order.
+    /// `order` must be <= storage.order()
+    pub fn truncated<S: Storage<T>>(storage: &S, order: usize) -> Self {
+        let mut output = Self::zeros(order);
+        copy_truncated(storage, &mut output);
+        output
+    }
+}
+
+impl<T: Scalar> Storage<T> for DynStorage<T> {
+    #[inline(always)]
+    fn order(&self) -> usize {
+        self.order
+    }
+
+    #[inline(always)]
+    fn get<const I: usize, const J: usize>(&self) -> T {
+        self.at(I, J)
+    }
+
+    #[inline(always)]
+    fn get_mut<const I: usize, const J: usize>(&mut self) -> &mut T {
+        self.at_mut(I, J)
+    }
+
+    #[inline(always)]
+    fn at(&self, i: usize, j: usize) -> T {
+        self.data[calculate_offset(i, j, self.order)]
+    }
+
+    #[inline(always)]
+    fn at_mut(&mut self, i: usize, j: usize) -> &mut T {
+        &mut self.data[calculate_offset(i, j, self.order)]
+    }
+
+    #[inline(always)]
+    fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+}
+
+/// Copy all elements of `input` whose position is covered by `output`.
+/// `output.order()` must be <= input.order()
+pub fn copy_truncated<T: Scalar, I: Storage<T>, O: Storage<T>>(input: &I, output: &mut O) {
+    let order = output.order();
+    for j in 0..=order {
+        for i in 0..=(order - j) {
+            *output.at_mut(i, j) = input.at(i, j);
+        }
+    }
 }
 
 /// Calculate the space required to hold a storage of a specific order.
@@ -29,16 +114,18 @@ const fn calculate_index<const I: usize, const J: usize, const ORDER: usize>() -
     J * (ORDER + 1) - ((J as isize - 1) * J as isize / 2) as usize + I
 }
 
+/// Calculate the index of an element at a position only known at runtime.
+pub const fn calculate_offset(i: usize, j: usize, order: usize) -> usize {
+    j * (order + 1) - j.saturating_sub(1) * j / 2 + i
+}
+
 /// Implement the Storage crate for some meaningful values.
 macro_rules! impl_storage_for_order {
     ( $order:expr ) => {
         impl<T: Scalar> Storage<T> for [T; calculate_space::<$order>()] {
-            const ORDER: usize = $order;
-            const SIZE: usize = calculate_space::<$order>();
-
             #[inline(always)]
-            fn zeros() -> Self {
-                [T::ZERO; calculate_space::<$order>()]
+            fn order(&self) -> usize {
+                $order
             }
 
             #[inline(always)]
@@ -50,6 +137,31 @@ macro_rules! impl_storage_for_order {
             fn get_mut<const I: usize, const J: usize>(&mut self) -> &mut T {
                 &mut self[calculate_index::<I, J, $order>()]
             }
+
+            #[inline(always)]
+            fn at(&self, i: usize, j: usize) -> T {
+                self[calculate_offset(i, j, $order)]
+            }
+
+            #[inline(always)]
+            fn at_mut(&mut self, i: usize, j: usize) -> &mut T {
+                &mut self[calculate_offset(i, j, $order)]
+            }
+
+            #[inline(always)]
+            fn as_slice(&self) -> &[T] {
+                self
+            }
+        }
+
+        impl<T: Scalar> FixedStorage<T> for [T; calculate_space::<$order>()] {
+            const ORDER: usize = $order;
+            const SIZE: usize = calculate_space::<$order>();
+
+            #[inline(always)]
+            fn zeros() -> Self {
+                [T::ZERO; calculate_space::<$order>()]
+            }
         }
     };
 }
@@ -58,10 +170,19 @@ impl_storage_for_order!(0);
 impl_storage_for_order!(1);
 impl_storage_for_order!(2);
 impl_storage_for_order!(3);
+impl_storage_for_order!(4);
+impl_storage_for_order!(5);
+impl_storage_for_order!(6);
+impl_storage_for_order!(7);
+impl_storage_for_order!(8);
+impl_storage_for_order!(9);
+impl_storage_for_order!(10);
 
 #[cfg(test)]
 mod tests {
-    use super::{calculate_index, calculate_space, Storage};
+    use super::{
+        calculate_index, calculate_offset, calculate_space, DynStorage, FixedStorage, Storage,
+    };
 
     #[test]
     fn test_index() {
@@ -83,6 +204,15 @@ mod tests {
         assert_eq!(calculate_index::<0, 5, 5>(), 20);
     }
 
+    #[test]
+    fn test_offset() {
+        assert_eq!(calculate_offset(0, 0, 1), calculate_index::<0, 0, 1>());
+        assert_eq!(calculate_offset(0, 3, 4), calculate_index::<0, 3, 4>());
+        assert_eq!(calculate_offset(2, 2, 4), calculate_index::<2, 2, 4>());
+        assert_eq!(calculate_offset(0, 5, 5), calculate_index::<0, 5, 5>());
+        assert_eq!(calculate_offset(3, 7, 10), calculate_index::<3, 7, 10>());
+    }
+
     #[test]
     fn test_space_calculation() {
         assert_eq!(calculate_space::<0>(), 1);
@@ -99,5 +229,25 @@ mod tests {
         assert_eq!(data.get::<0, 0>(), 0.0);
         *data.get_mut::<0, 0>() = 42.0;
         assert_eq!(data.get::<0, 0>(), 42.0);
+
+        let mut data = [0.0; calculate_space::<3>()];
+        *data.at_mut(2, 1) = 42.0;
+        assert_eq!(data.get::<2, 1>(), 42.0);
+        assert_eq!(data.at(2, 1), 42.0);
+    }
+
+    #[test]
+    fn test_dyn_storage() {
+        let mut data = <[f64; calculate_space::<3>()]>::zeros();
+        *data.get_mut::<1, 2>() = 42.0;
+
+        let dyn_data = DynStorage::truncated(&data, 3);
+        assert_eq!(dyn_data.order(), 3);
+        assert_eq!(dyn_data.as_slice(), data.as_slice());
+        assert_eq!(dyn_data.get::<1, 2>(), 42.0);
+
+        let truncated = DynStorage::truncated(&dyn_data, 2);
+        assert_eq!(truncated.as_slice().len(), calculate_space::<2>());
+        assert_eq!(truncated.at(1, 1), 0.0);
     }
 }
@@ -7,29 +7,30 @@ pub struct Index<const I: usize, const J: usize>;
 /// An marker trait indicating a compile-time index is valid for a specific order. This trait is sealed.
 pub trait SupportedIndex<const ORDER: usize>: Sealed {}
 
+/// Implement the marker trait for all indices of one degree (I + J) and all orders covering it.
 macro_rules! impl_supported_index {
-    ($( $order:expr ),* => ($i:expr, $j:expr)) => {
-        impl Sealed for Index<$i, $j> {}
-        $(
-            impl SupportedIndex<$order> for Index<$i, $j> {}
-        )*
+    ($orders:tt => $( ($i:literal, $j:literal) ),*) => {
+        $( impl_supported_index!(@index $orders => ($i, $j)); )*
     };
-    ($( $order:expr ),* => ($i:expr) <-> ($j:expr)) => {
+    (@index [$( $order:literal ),*] => ($i:literal, $j:literal)) => {
         impl Sealed for Index<$i, $j> {}
-        impl Sealed for Index<$j, $i> {}
         $(
             impl SupportedIndex<$order> for Index<$i, $j> {}
-            impl SupportedIndex<$order> for Index<$j, $i> {}
         )*
     };
 }
 
-impl_supported_index!(0, 1, 2, 3 => (0, 0));
-impl_supported_index!(1, 2, 3 => (1) <-> (0));
-impl_supported_index!(2, 3 => (1, 1));
-impl_supported_index!(2, 3 => (2) <-> (0));
-impl_supported_index!(3 => (3) <-> (0));
-impl_supported_index!(3 => (2) <-> (1));
+impl_supported_index!([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10] => (0, 0));
+impl_supported_index!([1, 2, 3, 4, 5, 6, 7, 8, 9, 10] => (1, 0), (0, 1));
+impl_supported_index!([2, 3, 4, 5, 6, 7, 8, 9, 10] => (2, 0), (1, 1), (0, 2));
+impl_supported_index!([3, 4, 5, 6, 7, 8, 9, 10] => (3, 0), (2, 1), (1, 2), (0, 3));
+impl_supported_index!([4, 5, 6, 7, 8, 9, 10] => (4, 0), (3, 1), (2, 2), (1, 3), (0, 4));
+impl_supported_index!([5, 6, 7, 8, 9, 10] => (5, 0), (4, 1), (3, 2), (2, 3), (1, 4), (0, 5));
+impl_supported_index!([6, 7, 8, 9, 10] => (6, 0), (5, 1), (4, 2), (3, 3), (2, 4), (1, 5), (0, 6));
+impl_supported_index!([7, 8, 9, 10] => (7, 0), (6, 1), (5, 2), (4, 3), (3, 4), (2, 5), (1, 6), (0, 7));
+impl_supported_index!([8, 9, 10] => (8, 0), (7, 1), (6, 2), (5, 3), (4, 4), (3, 5), (2, 6), (1, 7), (0, 8));
+impl_supported_index!([9, 10] => (9, 0), (8, 1), (7, 2), (6, 3), (5, 4), (4, 5), (3, 6), (2, 7), (1, 8), (0, 9));
+impl_supported_index!([10] => (10, 0), (9, 1), (8, 2), (7, 3), (6, 4), (5, 5), (4, 6), (3, 7), (2, 8), (1, 9), (0, 10));
 
 mod private {
     pub trait Sealed {}
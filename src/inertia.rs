@@ -0,0 +1,232 @@
+use crate::{
+    implementation::{principal_variances, symmetric_eigen, Storage},
+    Centroid, Point, Scalar,
+};
+
+/// The second-moment (inertia) tensor `[[μ20, μ11], [μ11, μ02]]` of an image or contour around its centroid.
+///
+/// Interpreting the intensities as a mass density, its trace `μ20 + μ02` is the polar moment of inertia around the centroid.
+/// The tensor is calculated by [`Central::inertia_tensor`](crate::Central::inertia_tensor).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InertiaTensor<T: Scalar> {
+    mass: T,
+    tensor: [[T; 2]; 2],
+    centroid: Option<Centroid<T>>,
+}
+
+/// The eigen-decomposition of an [`InertiaTensor`] into its principal axes.
+///
+/// The y-axis points downwards in images, such that positive angles rotate clockwise on screen.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrincipalAxes<T: Scalar> {
+    /// The principal moments `(λ_max, λ_min)`, i.e. the eigenvalues of the tensor.
+    pub moments: (T, T),
+    /// The unit vector along the major axis belonging to `λ_max`.
+    pub major: (T, T),
+    /// The unit vector along the minor axis belonging to `λ_min`, which is perpendicular to the major axis.
+    pub minor: (T, T),
+    /// The angle of the major axis towards the x-axis in radians within (-π/2, π/2].
+    pub angle: T,
+    /// Whether both principal moments are equal, e.g. for circles or squares.
+    /// Then every direction is a principal axis and the axes default to the x-axis and the y-axis.
+    pub isotropic: bool,
+}
+
+impl<T: Scalar> InertiaTensor<T> {
+    /// Extract the tensor from central moments of an order >= 2.
+    pub(crate) fn new<S: Storage<T>>(moments: &S, centroid: Option<Centroid<T>>) -> Self {
+        let (m20, m11, m02) = (moments.at(2, 0), moments.at(1, 1), moments.at(0, 2));
+        InertiaTensor {
+            mass: moments.at(0, 0),
+            tensor: [[m20, m11], [m11, m02]],
+            centroid,
+        }
+    }
+
+    /// The total mass `μ00`, i.e. the area of a contour or the sum of intensities of an image.
+    #[inline(always)]
+    pub fn mass(&self) -> T {
+        self.mass
+    }
+
+    /// The tensor as row-major matrix.
+    #[inline(always)]
+    pub fn tensor(&self) -> [[T; 2]; 2] {
+        self.tensor
+    }
+
+    /// The centroid the tensor is calculated around, if it is defined.
+    #[inline(always)]
+    pub fn centroid(&self) -> Option<Centroid<T>> {
+        self.centroid
+    }
+
+    /// Decompose the tensor into its principal moments and axes using a closed-form solver for symmetric 2x2 matrices.
+    ///
+    /// Principal moments within a relative tolerance of `sqrt(ε)` of the trace are considered equal and the tensor isotropic,
+    /// as rounding keeps them from being exactly equal for symmetric shapes at arbitrary positions and rotations.
+    /// This tolerance is specific to the principal axes: The [`Ellipse`](crate::Ellipse), the
+    /// [`ShapeDescriptors`](crate::ShapeDescriptors) and the [`Direction`](crate::Direction) keep the exact decomposition,
+    /// such that almost circular shapes still have an orientation.
+    pub fn principal_axes(&self) -> PrincipalAxes<T> {
+        let [[a, b], [_, c]] = self.tensor;
+        let (max, min, angle) = symmetric_eigen(a, b, c);
+
+        // Every direction is a principal axis if the moments only differ by rounding
+        let isotropic = max - min <= T::EPSILON.sqrt() * (a.abs() + c.abs());
+        let (max, min, angle) = match isotropic {
+            true => {
+                let mean = (a + c) * T::F1_2;
+                (mean, mean, T::ZERO)
+            }
+            false => (max, min, angle),
+        };
+        let (sin, cos) = (angle.sin(), angle.cos());
+        PrincipalAxes {
+            moments: (max, min),
+            major: (cos, sin),
+            minor: (-sin, cos),
+            angle,
+            isotropic,
+        }
+    }
+
+    /// The radius of gyration `sqrt((μ20 + μ02) / μ00)` around the centroid,
+    /// i.e. the distance from the centroid at which the whole mass would have the same polar moment of inertia.
+    /// It is `None` for degenerate contours or images without any area.
+    pub fn radius_of_gyration(&self) -> Option<T> {
+        self.squared_radius_of_gyration().map(T::sqrt)
+    }
+
+    /// The radius of gyration around an arbitrary point `p` following the parallel axis theorem,
+    /// i.e. `sqrt((μ20 + μ02) / μ00 + |p - centroid|²)`.
+    /// It is `None` for degenerate contours or images without any area.
+    pub fn radius_of_gyration_about<P: Point<T>>(&self, point: P) -> Option<T> {
+        let centroid = self.centroid?;
+        let (dx, dy) = (point.x() - centroid.x, point.y() - centroid.y);
+        self.squared_radius_of_gyration()
+            .map(|radius| dx.mul_add(dx, dy.mul_add(dy, radius)).sqrt())
+    }
+
+    /// The squared radius of gyration around the centroid, if the mass is not (almost) zero.
+    fn squared_radius_of_gyration(&self) -> Option<T> {
+        self.centroid?;
+        let [[a, b], [_, c]] = self.tensor;
+        principal_variances(self.mass, a, b, c).map(|(major, minor, _)| major + minor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Central, DynCentral, DynSpatial, GrayImage, InsufficientOrder, Spatial};
+
+    #[test]
+    fn test_rectangle() {
+        // A 6x2 rectangle rotated by 30 degrees around the origin
+        let angle = std::f64::consts::FRAC_PI_6;
+        let (sin, cos) = angle.sin_cos();
+        let points: Vec<_> = [(-3.0, -1.0), (3.0, -1.0), (3.0, 1.0), (-3.0, 1.0)]
+            .iter()
+            .map(|(x, y)| (x * cos - y * sin + 10.0, x * sin + y * cos + 20.0))
+            .collect();
+        let moments = Central::from(&points.iter().collect::<Spatial<f64, 2>>());
+        let tensor = moments.inertia_tensor();
+        assert_abs_diff_eq!(tensor.mass(), 12.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(tensor.tensor()[0][1], tensor.tensor()[1][0]);
+
+        // The principal moments are b * h³ / 12 and h * b³ / 12
+        let axes = tensor.principal_axes();
+        assert!(!axes.isotropic);
+        assert_abs_diff_eq!(axes.moments.0, 36.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(axes.moments.1, 4.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(axes.angle, angle, epsilon = 10e-9);
+        assert_abs_diff_eq!(axes.major.0, cos, epsilon = 10e-9);
+        assert_abs_diff_eq!(axes.major.1, sin, epsilon = 10e-9);
+        assert_abs_diff_eq!(axes.minor.0, -sin, epsilon = 10e-9);
+        assert_abs_diff_eq!(axes.minor.1, cos, epsilon = 10e-9);
+
+        // The radius of gyration follows the parallel axis theorem
+        let radius = tensor.radius_of_gyration().unwrap();
+        assert_abs_diff_eq!(radius, (40.0f64 / 12.0).sqrt(), epsilon = 10e-9);
+        assert_abs_diff_eq!(
+            tensor.radius_of_gyration_about((13.0, 24.0)).unwrap(),
+            (40.0f64 / 12.0 + 25.0).sqrt(),
+            epsilon = 10e-9
+        );
+        assert_abs_diff_eq!(
+            tensor
+                .radius_of_gyration_about(tensor.centroid().unwrap())
+                .unwrap(),
+            radius,
+            epsilon = 10e-9
+        );
+    }
+
+    #[test]
+    fn test_isotropic() {
+        // A disk centered on a pixel is symmetric under rotations by 90 degrees
+        let size: usize = 41;
+        let pixels: Vec<bool> = (0..size * size)
+            .map(|index| {
+                let (x, y) = ((index % size) as f64 - 20.0, (index / size) as f64 - 20.0);
+                x * x + y * y <= 300.0
+            })
+            .collect();
+        let image = GrayImage::new(size, size, &pixels[..]).unwrap();
+        let tensor = Central::from(&Spatial::<f64, 2>::from_image(&image)).inertia_tensor();
+
+        let axes = tensor.principal_axes();
+        assert!(axes.isotropic);
+        assert_eq!(axes.moments.0, axes.moments.1);
+        assert_eq!(
+            (axes.major, axes.minor, axes.angle),
+            ((1.0, 0.0), (0.0, 1.0), 0.0)
+        );
+
+        // The same holds for squares
+        let points = [(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)];
+        let square = Central::from(&points.iter().collect::<Spatial<f64, 3>>());
+        assert!(square.inertia_tensor().principal_axes().isotropic);
+    }
+
+    #[test]
+    fn test_isotropic_rounding() {
+        // A regular heptagon has equal principal moments, which only differ by rounding when it is moved and rotated
+        let points: Vec<_> = (0..7)
+            .map(|index| {
+                let angle = 0.3 + index as f64 * 2.0 * std::f64::consts::PI / 7.0;
+                (123.5 + 3.0 * angle.cos(), 67.25 + 3.0 * angle.sin())
+            })
+            .collect();
+        let tensor = Central::from(&points.iter().collect::<Spatial<f64, 2>>()).inertia_tensor();
+        let [[a, b], [_, c]] = tensor.tensor();
+        let (max, min, _) = crate::implementation::symmetric_eigen(a, b, c);
+        assert!(max > min);
+        assert_abs_diff_eq!(max, min, epsilon = 10e-9 * max);
+
+        let axes = tensor.principal_axes();
+        assert!(axes.isotropic);
+        assert_eq!(axes.moments.0, axes.moments.1);
+        assert_eq!(axes.angle, 0.0);
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let line = [(1.0, 1.0), (4.0, 4.0)];
+        let tensor = Central::from(&line.iter().collect::<Spatial<f64, 2>>()).inertia_tensor();
+        assert!(tensor.centroid().is_none());
+        assert!(tensor.radius_of_gyration().is_none());
+        assert!(tensor.radius_of_gyration_about((0.0, 0.0)).is_none());
+
+        let moments = DynCentral::from(&DynSpatial::from_points(1, line.iter()));
+        assert_eq!(
+            moments.inertia_tensor(),
+            Err(InsufficientOrder {
+                required: 2,
+                available: 1
+            })
+        );
+    }
+}
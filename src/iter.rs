@@ -0,0 +1,92 @@
+use std::iter::FusedIterator;
+
+use crate::Scalar;
+
+/// An iterator over moments and their indices as `(i, j, value)` triples.
+///
+/// The moments are yielded in their storage layout: Ordered by ascending `j` first and ascending `i` second,
+/// i.e. `(0, 0), (1, 0), ..., (ORDER, 0), (0, 1), ..., (ORDER - 1, 1), ..., (0, ORDER)`.
+#[derive(Debug, Clone)]
+pub struct Iter<'a, T: Scalar> {
+    values: std::slice::Iter<'a, T>,
+    order: usize,
+    i: usize,
+    j: usize,
+}
+
+impl<'a, T: Scalar> Iter<'a, T> {
+    pub(crate) fn new(values: &'a [T], order: usize) -> Self {
+        Iter {
+            values: values.iter(),
+            order,
+            i: 0,
+            j: 0,
+        }
+    }
+}
+
+impl<'a, T: Scalar> Iterator for Iter<'a, T> {
+    type Item = (usize, usize, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = *self.values.next()?;
+        let (i, j) = (self.i, self.j);
+
+        // Move to the next row of the triangular layout if the current one is exhausted
+        if i + j >= self.order {
+            self.i = 0;
+            self.j += 1;
+        } else {
+            self.i += 1;
+        }
+
+        Some((i, j, value))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl<'a, T: Scalar> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T: Scalar> FusedIterator for Iter<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::Iter;
+
+    #[test]
+    fn test_order() {
+        let values = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+        let indices: Vec<_> = Iter::new(&values, 2).collect();
+        assert_eq!(
+            indices,
+            vec![
+                (0, 0, 0.0),
+                (1, 0, 1.0),
+                (2, 0, 2.0),
+                (0, 1, 3.0),
+                (1, 1, 4.0),
+                (0, 2, 5.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_size() {
+        let values = [0.0; 10];
+        let mut iter = Iter::new(&values, 3);
+        assert_eq!(iter.len(), 10);
+        iter.next();
+        assert_eq!(iter.len(), 9);
+        assert_eq!(iter.last(), Some((0, 3, 0.0)));
+
+        let values = [42.0];
+        assert_eq!(
+            Iter::new(&values, 0).collect::<Vec<_>>(),
+            vec![(0, 0, 42.0)]
+        );
+    }
+}
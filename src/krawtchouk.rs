@@ -0,0 +1,165 @@
+use crate::{
+    implementation::{krawtchouk_polynomials, separable_inverse, separable_transform},
+    Image, Scalar,
+};
+
+/// The weighted Krawtchouk moments `Q_pq` of an image for `p <= order_x` and `q <= order_y`.
+///
+/// The moments are `Q_pq = Σ_x Σ_y K_p(x; p_x) K_q(y; p_y) f(x, y)` with the orthonormal weighted Krawtchouk polynomials `K_n`.
+/// While moments of low order cover the whole image for `p_x = p_y = 0.5`, they focus on the region around
+/// `(p_x * (width - 1), p_y * (height - 1))` otherwise, making them suitable as local features.
+/// Like all discrete moments, [`Krawtchouk::reconstruct`] restores the image exactly if the orders are `width - 1` and `height - 1`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Krawtchouk<T: Scalar> {
+    width: usize,
+    height: usize,
+    order_x: usize,
+    order_y: usize,
+    p: (T, T),
+    moments: Vec<T>,
+}
+
+impl<T: Scalar> Krawtchouk<T> {
+    /// Calculate the moments of a grayscale or binary image up to specific orders in x and y.
+    /// The orders are limited to `width - 1` and `height - 1`, beyond which the polynomials are not defined.
+    /// It is `None` unless the parameters `p_x` and `p_y` are within (0, 1), where the polynomials are defined.
+    pub fn from_image<I: Image<T>>(
+        image: &I,
+        order_x: usize,
+        order_y: usize,
+        p_x: T,
+        p_y: T,
+    ) -> Option<Self> {
+        let valid = |p: T| p > T::ZERO && p < T::ONE;
+        if !valid(p_x) || !valid(p_y) {
+            return None;
+        }
+
+        let (width, height) = (image.width(), image.height());
+        let order_x = order_x.min(width.saturating_sub(1));
+        let order_y = order_y.min(height.saturating_sub(1));
+        let columns = krawtchouk_polynomials(width, order_x, p_x);
+        let rows = krawtchouk_polynomials(height, order_y, p_y);
+
+        Some(Krawtchouk {
+            width,
+            height,
+            order_x,
+            order_y,
+            p: (p_x, p_y),
+            moments: separable_transform(image, &columns, &rows, order_x, order_y),
+        })
+    }
+
+    /// The width of the image the moments were calculated from.
+    #[inline(always)]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The height of the image the moments were calculated from.
+    #[inline(always)]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The order up to which the moments were calculated in x.
+    #[inline(always)]
+    pub fn order_x(&self) -> usize {
+        self.order_x
+    }
+
+    /// The order up to which the moments were calculated in y.
+    #[inline(always)]
+    pub fn order_y(&self) -> usize {
+        self.order_y
+    }
+
+    /// The parameters `(p_x, p_y)` of the polynomials.
+    #[inline(always)]
+    pub fn p(&self) -> (T, T) {
+        self.p
+    }
+
+    /// Get the moment at a specific position, if it is covered by the orders.
+    #[inline(always)]
+    pub fn get(&self, p: usize, q: usize) -> Option<T> {
+        match p <= self.order_x && q <= self.order_y {
+            true => Some(self.moments[q * (self.order_x + 1) + p]),
+            false => None,
+        }
+    }
+
+    /// Restore the row-major pixels of the image from the moments by the inverse transform.
+    pub fn reconstruct(&self) -> Vec<T> {
+        let (width, height) = (self.width, self.height);
+        let columns = krawtchouk_polynomials(width, self.order_x, self.p.0);
+        let rows = krawtchouk_polynomials(height, self.order_y, self.p.1);
+        separable_inverse(
+            &self.moments,
+            &columns,
+            &rows,
+            (width, height),
+            self.order_x,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use crate::{GrayImage, Krawtchouk};
+
+    fn pixels(width: usize, height: usize) -> Vec<f64> {
+        (0..width * height)
+            .map(|index| ((index * 7919) % 256) as f64 / 255.0)
+            .collect()
+    }
+
+    #[test]
+    fn test_exact_reconstruction() {
+        let (width, height) = (96, 64);
+        let pixels = pixels(width, height);
+        let image = GrayImage::new(width, height, &pixels).unwrap();
+        for (p_x, p_y) in [(0.5, 0.5), (0.2, 0.7)] {
+            let moments = Krawtchouk::from_image(&image, 200, 200, p_x, p_y).unwrap();
+            assert_eq!((moments.order_x(), moments.order_y()), (95, 63));
+            assert_eq!(moments.p(), (p_x, p_y));
+            for (actual, expected) in moments.reconstruct().iter().zip(&pixels) {
+                assert_abs_diff_eq!(*actual, *expected, epsilon = 10e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_locality() {
+        // A single bright pixel in the top left corner dominates Q_00 only if the polynomials focus there
+        let (width, height) = (32, 32);
+        let mut pixels = vec![0.0; width * height];
+        pixels[3 * width + 3] = 1.0;
+        let image = GrayImage::new(width, height, &pixels).unwrap();
+
+        let global = Krawtchouk::<f64>::from_image(&image, 2, 2, 0.5, 0.5).unwrap();
+        let local = Krawtchouk::<f64>::from_image(&image, 2, 2, 0.1, 0.1).unwrap();
+        assert!(local.get(0, 0).unwrap() > 100.0 * global.get(0, 0).unwrap());
+        assert!(local.get(3, 0).is_none());
+        assert_eq!((local.width(), local.height()), (32, 32));
+    }
+
+    #[test]
+    fn test_invalid_p() {
+        // The polynomials are only defined for p within (0, 1), otherwise the weights are NaN
+        let pixels = pixels(8, 8);
+        let image = GrayImage::new(8, 8, &pixels).unwrap();
+        for (p_x, p_y) in [
+            (0.0, 0.5),
+            (0.5, 1.0),
+            (-0.2, 0.5),
+            (0.5, 1.5),
+            (f64::NAN, 0.5),
+        ] {
+            assert!(Krawtchouk::<f64>::from_image(&image, 2, 2, p_x, p_y).is_none());
+        }
+    }
+}
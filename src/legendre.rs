@@ -0,0 +1,287 @@
+use crate::{
+    implementation::{
+        binomial, bounding_box, legendre_coefficients, legendre_polynomials, tabulate,
+        triangular_inverse, triangular_transform, DynStorage, Storage,
+    },
+    DynSpatial, Image, Iter, Order, Point, Scalar, Spatial, SupportedOrder,
+};
+
+/// The orthogonal Legendre moments `λ_pq` of an image normalized to `[-1, 1]²` for `p + q <= order`.
+///
+/// The moments are `λ_pq = (2p + 1)(2q + 1) / 4 * ∫∫ P_p(x) P_q(y) f(x, y) dx dy` with the Legendre polynomials `P_n`.
+/// Due to their orthogonality, the image may be approximated from them by [`Legendre::reconstruct`].
+///
+/// As the moments are linear combinations of geometric moments, they are exact for polygon contours.
+/// Then, the box given by its minimal and maximal corner is mapped onto `[-1, 1]²`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Legendre<T: Scalar>(pub(crate) DynStorage<T>);
+
+impl<T: Scalar> Legendre<T> {
+    /// Calculate the moments of a grayscale or binary image up to a specific order.
+    /// The pixels are sampled at their centers, i.e. pixel (x, y) maps to `((2x + 1) / width - 1, (2y + 1) / height - 1)`.
+    pub fn from_image<I: Image<T>>(image: &I, order: usize) -> Self {
+        let (width, height) = (image.width(), image.height());
+        if width == 0 || height == 0 {
+            return Legendre(DynStorage::zeros(order));
+        }
+
+        let columns = tabulate(width, order, legendre_polynomials);
+        let rows = tabulate(height, order, legendre_polynomials);
+        let mut moments = triangular_transform(image, &columns, &rows, order);
+
+        let pixel_area = T::TWO / T::from_usize(width) * T::TWO / T::from_usize(height);
+        for q in 0..=order {
+            for p in 0..=order - q {
+                *moments.at_mut(p, q) *=
+                    T::from_usize((2 * p + 1) * (2 * q + 1)) / T::from_usize(4) * pixel_area;
+            }
+        }
+
+        Legendre(moments)
+    }
+
+    /// Calculate the exact moments of a polygon from its spatial moments,
+    /// mapping the box between the corners `min` and `max` onto `[-1, 1]²`.
+    pub fn from_spatial<const ORDER: usize>(
+        moments: &Spatial<T, ORDER>,
+        min: (T, T),
+        max: (T, T),
+    ) -> Self
+    where
+        Order<ORDER>: SupportedOrder<T>,
+    {
+        Self::from_storage(&moments.0, min, max)
+    }
+
+    /// Calculate the exact moments of the polygon given by the points of a contour up to a specific order,
+    /// mapping its bounding box onto `[-1, 1]²`.
+    pub fn from_points<P: Point<T>, I: IntoIterator<Item = P>>(order: usize, points: I) -> Self {
+        let points: Vec<P> = points.into_iter().collect();
+        match bounding_box(&points) {
+            Some((min, max)) => Self::from_points_in_box(order, min, max, points),
+            None => Legendre(DynStorage::zeros(order)),
+        }
+    }
+
+    /// Calculate the exact moments of the polygon given by the points of a contour up to a specific order,
+    /// mapping the box between the corners `min` and `max` onto `[-1, 1]²`.
+    pub fn from_points_in_box<P: Point<T>, I: IntoIterator<Item = P>>(
+        order: usize,
+        min: (T, T),
+        max: (T, T),
+        points: I,
+    ) -> Self {
+        Self::from_storage(&DynSpatial::from_points(order, points).0, min, max)
+    }
+
+    /// Calculate the moments from geometric ones by mapping them into the box first.
+    fn from_storage<S: Storage<T>>(moments: &S, min: (T, T), max: (T, T)) -> Self {
+        let order = moments.order();
+
+        // The affine mapping x' = scale * x + offset onto [-1, 1] for both axes
+        let axis = |min: T, max: T| {
+            let extent = max - min;
+            match extent > T::EPSILON {
+                true => (T::TWO / extent, -(max + min) / extent),
+                false => (T::ONE, -min),
+            }
+        };
+        let (x, y) = (axis(min.0, max.0), axis(min.1, max.1));
+        let powers = |(scale, offset): (T, T), i: usize, k: usize| {
+            binomial::<T>(i, k) * scale.powi(k as i32) * offset.powi((i - k) as i32)
+        };
+
+        let mut mapped = DynStorage::zeros(order);
+        for j in 0..=order {
+            for i in 0..=order - j {
+                let mut sum = T::ZERO;
+                for k in 0..=i {
+                    for l in 0..=j {
+                        sum = (powers(x, i, k) * powers(y, j, l)).mul_add(moments.at(k, l), sum);
+                    }
+                }
+                *mapped.at_mut(i, j) = sum * x.0 * y.0;
+            }
+        }
+
+        let coefficients = legendre_coefficients::<T>(order);
+        let mut output = DynStorage::zeros(order);
+        for q in 0..=order {
+            for p in 0..=order - q {
+                let mut sum = T::ZERO;
+                for k in 0..=p {
+                    for l in 0..=q {
+                        let coefficient =
+                            coefficients[p * (order + 1) + k] * coefficients[q * (order + 1) + l];
+                        sum = coefficient.mul_add(mapped.at(k, l), sum);
+                    }
+                }
+                *output.at_mut(p, q) =
+                    sum * T::from_usize((2 * p + 1) * (2 * q + 1)) / T::from_usize(4);
+            }
+        }
+
+        Legendre(output)
+    }
+
+    /// The order up to which the moments were calculated.
+    #[inline(always)]
+    pub fn order(&self) -> usize {
+        self.0.order()
+    }
+
+    /// Get the moment at a specific position, if it is covered by the order.
+    #[inline(always)]
+    pub fn get(&self, p: usize, q: usize) -> Option<T> {
+        match p <= self.0.order() && q <= self.0.order() - p {
+            true => Some(self.0.at(p, q)),
+            false => None,
+        }
+    }
+
+    /// Iterate over all moments as `(p, q, value)` triples in the order documented at [`Iter`].
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.0.as_slice(), self.0.order())
+    }
+
+    /// Collect all moments into a vector ordered as documented at [`Iter`].
+    #[inline(always)]
+    pub fn to_vec(&self) -> Vec<T> {
+        self.0.as_slice().to_vec()
+    }
+
+    /// Approximate the image from the moments as row-major pixels sampled at their centers.
+    /// The size of the output is arbitrary, such that it does not need to match the original image.
+    pub fn reconstruct(&self, width: usize, height: usize) -> Vec<T> {
+        let order = self.0.order();
+        let columns = tabulate(width, order, legendre_polynomials);
+        let rows = tabulate(height, order, legendre_polynomials);
+        triangular_inverse(&self.0, &columns, &rows, (width, height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use crate::{GrayImage, Legendre, Spatial};
+
+    #[test]
+    fn test_polynomial() {
+        // An image equal to P_1(x) * P_2(y) only yields λ_12 = 1
+        let (width, height) = (80, 60);
+        let pixels: Vec<f64> = (0..width * height)
+            .map(|index| {
+                let x = (2 * (index % width) + 1) as f64 / width as f64 - 1.0;
+                let y = (2 * (index / width) + 1) as f64 / height as f64 - 1.0;
+                x * (3.0 * y * y - 1.0) / 2.0
+            })
+            .collect();
+        let image = GrayImage::new(width, height, &pixels).unwrap();
+        let moments = Legendre::<f64>::from_image(&image, 4);
+
+        assert_eq!(moments.order(), 4);
+        assert_eq!(moments.iter().count(), 15);
+        for (p, q, value) in moments.iter() {
+            let expected = match (p, q) {
+                (1, 2) => 1.0,
+                _ => 0.0,
+            };
+            assert_abs_diff_eq!(value, expected, epsilon = 10e-3);
+        }
+        assert!(moments.get(2, 3).is_none());
+        assert!(moments.get(usize::MAX, 1).is_none());
+    }
+
+    #[test]
+    fn test_reconstruction() {
+        // A smooth image is recovered well from a moderate number of moments
+        let (width, height) = (96, 64);
+        let pixels: Vec<f64> = (0..width * height)
+            .map(|index| {
+                let x = (2 * (index % width) + 1) as f64 / width as f64 - 1.0;
+                let y = (2 * (index / width) + 1) as f64 / height as f64 - 1.0;
+                (-(x * x + 2.0 * y * y)).exp()
+            })
+            .collect();
+        let image = GrayImage::new(width, height, &pixels).unwrap();
+
+        let error = |order: usize| {
+            Legendre::<f64>::from_image(&image, order)
+                .reconstruct(width, height)
+                .iter()
+                .zip(&pixels)
+                .map(|(actual, expected)| (actual - expected).abs())
+                .fold(0.0f64, f64::max)
+        };
+        assert!(error(2) > error(4));
+        assert!(error(4) > error(8));
+        assert!(error(8) < 10e-3);
+    }
+
+    #[test]
+    fn test_empty() {
+        let image = GrayImage::<f32>::new(0, 3, &[]).unwrap();
+        let moments = Legendre::<f32>::from_image(&image, 2);
+        assert_eq!(moments.to_vec(), vec![0.0; 6]);
+        assert_eq!(moments.reconstruct(2, 1), vec![0.0; 2]);
+    }
+
+    #[test]
+    fn test_polygon() {
+        // The rectangle covers the right half of the box [-1, 1]², i.e. x in [0, 1]
+        let points = [(0.0, -1.0), (1.0, -1.0), (1.0, 1.0), (0.0, 1.0)];
+        let moments = Legendre::from_points_in_box(4, (-1.0, -1.0), (1.0, 1.0), points);
+        let expected = [
+            (0, 0, 0.5),
+            (1, 0, 0.75),
+            (2, 0, 0.0),
+            (3, 0, -7.0 / 16.0),
+            (0, 1, 0.0),
+            (1, 2, 0.0),
+        ];
+        for (p, q, value) in expected {
+            assert_abs_diff_eq!(moments.get(p, q).unwrap(), value, epsilon = 10e-12);
+        }
+
+        // Translating and scaling the contour along with its box keeps the moments
+        let transformed = points.iter().map(|(x, y)| (3.0 * x + 7.0, 5.0 * y - 2.0));
+        let spatial: Spatial<f64, 4> = transformed.clone().collect();
+        let actual = Legendre::from_spatial(&spatial, (4.0, -7.0), (10.0, 3.0));
+        for (expected, actual) in moments.iter().zip(actual.iter()) {
+            assert_abs_diff_eq!(expected.2, actual.2, epsilon = 10e-9);
+        }
+        let actual = Legendre::from_points(4, transformed);
+        assert_abs_diff_eq!(actual.get(0, 0).unwrap(), 1.0, epsilon = 10e-12);
+        assert!(Legendre::<f64>::from_points(2, Vec::<(f64, f64)>::new())
+            .iter()
+            .all(|(_, _, value)| value == 0.0));
+    }
+
+    #[test]
+    fn test_polygon_image() {
+        // The exact moments of a triangle approximate those sampled from pixels
+        let (width, height) = (128, 128);
+        let pixels: Vec<f64> = (0..width * height)
+            .map(|index| {
+                let (x, y) = ((index % width) as f64 + 0.5, (index / width) as f64 + 0.5);
+                match y > 16.0 && x > 16.0 && x + y < 128.0 {
+                    true => 1.0,
+                    false => 0.0,
+                }
+            })
+            .collect();
+        let image = GrayImage::new(width, height, &pixels).unwrap();
+        let expected = Legendre::<f64>::from_image(&image, 6);
+        let actual = Legendre::from_points_in_box(
+            6,
+            (0.0, 0.0),
+            (128.0, 128.0),
+            [(16.0, 16.0), (112.0, 16.0), (16.0, 112.0)],
+        );
+        for (expected, actual) in expected.iter().zip(actual.iter()) {
+            assert_abs_diff_eq!(expected.2, actual.2, epsilon = 0.05);
+        }
+    }
+}
@@ -11,20 +11,74 @@
     unused_qualifications
 )]
 
+mod affine;
+mod blur;
 mod central;
+mod centroid;
+mod complex;
+mod direction;
+mod disk;
+mod dyn_central;
+mod dyn_normalized_central;
+mod dyn_spatial;
+mod ellipse;
+mod error;
+mod flusser;
+mod fourier_mellin;
+mod gaussian_hermite;
+mod hu;
+mod image;
 /// The actual implementation details.
 mod implementation;
 mod index;
+mod inertia;
+mod iter;
+mod krawtchouk;
+mod legendre;
+mod matching;
+mod moment_set;
 mod moments;
 mod normalized_central;
+mod opencv;
 mod order;
 mod primitives;
+mod pseudo_zernike;
+mod shape_descriptors;
 mod spatial;
+mod tchebichef;
+mod zernike;
 
+pub use self::affine::Affine;
+pub use self::blur::{Blur, RotationBlur};
 pub use self::central::Central;
+pub use self::centroid::Centroid;
+pub use self::complex::Complex;
+pub use self::direction::Direction;
+pub use self::disk::DiskMapping;
+pub use self::dyn_central::DynCentral;
+pub use self::dyn_normalized_central::DynNormalizedCentral;
+pub use self::dyn_spatial::DynSpatial;
+pub use self::ellipse::Ellipse;
+pub use self::error::InsufficientOrder;
+pub use self::flusser::Flusser;
+pub use self::fourier_mellin::OrthogonalFourierMellin;
+pub use self::gaussian_hermite::GaussianHermite;
+pub use self::hu::Hu;
+pub use self::image::GrayImage;
 pub use self::index::{Index, SupportedIndex};
+pub use self::inertia::{InertiaTensor, PrincipalAxes};
+pub use self::iter::Iter;
+pub use self::krawtchouk::Krawtchouk;
+pub use self::legendre::Legendre;
+pub use self::matching::{match_shapes, MatchMethod};
+pub use self::moment_set::MomentSet;
 pub use self::moments::Moments;
 pub use self::normalized_central::NormalizedCentral;
+pub use self::opencv::OpenCvMoments;
 pub use self::order::{Order, SupportedOrder};
-pub use self::primitives::{Point, Scalar};
+pub use self::primitives::{Image, Point, Scalar};
+pub use self::pseudo_zernike::PseudoZernike;
+pub use self::shape_descriptors::ShapeDescriptors;
 pub use self::spatial::Spatial;
+pub use self::tchebichef::Tchebichef;
+pub use self::zernike::Zernike;
@@ -0,0 +1,135 @@
+use crate::{Hu, Point, Scalar};
+
+/// The methods for comparing shapes by their Hu invariants, equivalent to those of `cv::matchShapes`.
+///
+/// Given the invariants `h` of the shapes A and B, the methods use `m = sign(h) * log10(|h|)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatchMethod {
+    /// The sum of `|1 / m_A - 1 / m_B|` (`CONTOURS_MATCH_I1`).
+    I1,
+    /// The sum of `|m_A - m_B|` (`CONTOURS_MATCH_I2`).
+    I2,
+    /// The maximum of `|m_A - m_B| / |m_A|` (`CONTOURS_MATCH_I3`).
+    I3,
+}
+
+impl<T: Scalar> Hu<T> {
+    /// Calculate the distance to the shape described by other invariants like `cv::matchShapes`.
+    /// As in OpenCV, pairs of invariants are skipped if one of them has a magnitude below 1e-5.
+    /// If all invariants of exactly one of the shapes are 0, e.g. for an empty contour, the distance is `T::MAX`.
+    pub fn match_shape(&self, other: &Self, method: MatchMethod) -> T {
+        let eps = T::ONE / T::from_usize(100_000);
+        let mut result = T::ZERO;
+        let (mut any_a, mut any_b) = (false, false);
+
+        for (a, b) in self.invariants().iter().zip(other.invariants().iter()) {
+            let (abs_a, abs_b) = (a.abs(), b.abs());
+            any_a |= abs_a > T::ZERO;
+            any_b |= abs_b > T::ZERO;
+            if abs_a <= eps || abs_b <= eps {
+                continue;
+            }
+
+            let m_a = T::ONE.copysign(*a) * abs_a.log10();
+            let m_b = T::ONE.copysign(*b) * abs_b.log10();
+            match method {
+                MatchMethod::I1 => result += (T::ONE / m_b - T::ONE / m_a).abs(),
+                MatchMethod::I2 => result += (m_b - m_a).abs(),
+                MatchMethod::I3 => {
+                    let distance = ((m_a - m_b) / m_a).abs();
+                    if result < distance {
+                        result = distance;
+                    }
+                }
+            }
+        }
+
+        match any_a == any_b {
+            true => result,
+            false => T::MAX,
+        }
+    }
+}
+
+/// Calculate the distance between two contours like `cv::matchShapes`.
+pub fn match_shapes<T, P1, P2, A, B>(a: A, b: B, method: MatchMethod) -> T
+where
+    T: Scalar,
+    P1: Point<T>,
+    P2: Point<T>,
+    A: IntoIterator<Item = P1>,
+    B: IntoIterator<Item = P2>,
+{
+    let a: Hu<T> = a.into_iter().collect();
+    let b: Hu<T> = b.into_iter().collect();
+    a.match_shape(&b, method)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::{assert_abs_diff_eq, assert_relative_eq};
+
+    use crate::{match_shapes, Hu, MatchMethod};
+
+    const POINTS: [(f64, f64); 7] = [
+        (1.0, 2.0),
+        (7.0, 1.0),
+        (9.0, 6.0),
+        (6.0, 5.0),
+        (4.0, 9.0),
+        (3.0, 4.0),
+        (2.0, 5.0),
+    ];
+
+    const OTHER_POINTS: [(f64, f64); 5] =
+        [(0.0, 0.0), (10.0, 0.0), (12.0, 3.0), (4.0, 5.0), (1.0, 3.0)];
+
+    #[test]
+    fn test_identical() {
+        let transformed: Vec<_> = POINTS.iter().map(|(x, y)| (-3.0 * y, 3.0 * x)).collect();
+        for method in [MatchMethod::I1, MatchMethod::I2, MatchMethod::I3] {
+            assert_abs_diff_eq!(
+                match_shapes(POINTS.iter(), transformed.iter(), method),
+                0.0,
+                epsilon = 10e-9
+            );
+        }
+    }
+
+    #[test]
+    fn test_methods() {
+        // The reference values of `cv::matchShapes` for both contours, computed by a line-by-line port of
+        // `cv::moments`, `cv::HuMoments` and `cv::matchShapes` of OpenCV 4.x to double-precision Python
+        let a: Hu<f64> = POINTS.iter().collect();
+        let b: Hu<f64> = OTHER_POINTS.iter().collect();
+        let expected = [
+            (MatchMethod::I1, 0.540797856619927, 0.540797856619927),
+            (MatchMethod::I2, 2.419737988130164, 2.419737988130164),
+            (MatchMethod::I3, 0.5281296857761573, 1.1192263421886435),
+        ];
+        for (method, a_to_b, b_to_a) in expected {
+            assert_relative_eq!(a.match_shape(&b, method), a_to_b, max_relative = 10e-9);
+            assert_relative_eq!(b.match_shape(&a, method), b_to_a, max_relative = 10e-9);
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        // Like OpenCV, an empty contour does not match any shape but another empty contour
+        let empty: [(f64, f64); 0] = [];
+        for method in [MatchMethod::I1, MatchMethod::I2, MatchMethod::I3] {
+            assert_eq!(match_shapes(empty.iter(), POINTS.iter(), method), f64::MAX);
+            assert_eq!(match_shapes(POINTS.iter(), empty.iter(), method), f64::MAX);
+            assert_eq!(match_shapes(empty.iter(), empty.iter(), method), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_tiny_invariants() {
+        let a = Hu::from_invariants([0.1, 1e-6, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        let b = Hu::from_invariants([0.01, 0.5, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_abs_diff_eq!(a.match_shape(&b, MatchMethod::I2), 1.0, epsilon = 10e-12);
+        assert_abs_diff_eq!(a.match_shape(&b, MatchMethod::I1), 0.5, epsilon = 10e-12);
+        assert_abs_diff_eq!(a.match_shape(&b, MatchMethod::I3), 1.0, epsilon = 10e-12);
+    }
+}
@@ -0,0 +1,123 @@
+use std::{cell::OnceCell, iter::FromIterator};
+
+use crate::{
+    implementation::calculate_centroid, Central, Centroid, NormalizedCentral, Order, Point, Scalar,
+    Spatial, SupportedOrder,
+};
+
+/// The spatial, central and normalized central moments of an image or contour bundled together.
+/// The derived moments are calculated lazily on first access and cached afterwards.
+#[derive(Debug, Clone)]
+pub struct MomentSet<T: Scalar, const ORDER: usize>
+where
+    Order<ORDER>: SupportedOrder<T>,
+{
+    spatial: Spatial<T, ORDER>,
+    centroid: OnceCell<Option<Centroid<T>>>,
+    central: OnceCell<Central<T, ORDER>>,
+    normalized_central: OnceCell<NormalizedCentral<T, ORDER>>,
+}
+
+impl<T: Scalar, const ORDER: usize> MomentSet<T, ORDER>
+where
+    Order<ORDER>: SupportedOrder<T>,
+{
+    /// The raw, spatial moments.
+    #[inline(always)]
+    pub fn spatial(&self) -> &Spatial<T, ORDER> {
+        &self.spatial
+    }
+
+    /// The centroid, which is calculated on first access and `None` if it is not defined.
+    pub fn centroid(&self) -> Option<Centroid<T>> {
+        *self
+            .centroid
+            .get_or_init(|| calculate_centroid(&self.spatial.0))
+    }
+
+    /// The central moments, which are calculated on first access.
+    pub fn central(&self) -> &Central<T, ORDER> {
+        self.central
+            .get_or_init(|| Central::with_centroid(&self.spatial, self.centroid()))
+    }
+
+    /// The normalized central moments, which are calculated on first access.
+    pub fn normalized_central(&self) -> &NormalizedCentral<T, ORDER> {
+        self.normalized_central
+            .get_or_init(|| NormalizedCentral::from(self.central()))
+    }
+
+    /// Convert the bundle back into its spatial moments, dropping all cached moments.
+    #[inline(always)]
+    pub fn into_spatial(self) -> Spatial<T, ORDER> {
+        self.spatial
+    }
+}
+
+impl<T: Scalar, const ORDER: usize> From<Spatial<T, ORDER>> for MomentSet<T, ORDER>
+where
+    Order<ORDER>: SupportedOrder<T>,
+{
+    fn from(spatial: Spatial<T, ORDER>) -> Self {
+        MomentSet {
+            spatial,
+            centroid: OnceCell::new(),
+            central: OnceCell::new(),
+            normalized_central: OnceCell::new(),
+        }
+    }
+}
+
+impl<T: Scalar, P: Point<T>, const ORDER: usize> FromIterator<P> for MomentSet<T, ORDER>
+where
+    Order<ORDER>: SupportedOrder<T>,
+{
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Spatial<T, ORDER>>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Central, MomentSet, NormalizedCentral, Spatial};
+
+    #[test]
+    fn test_consistency() {
+        // A clockwise right triangle with legs of 3 and 4 at the highest supported order
+        let points = [(10.0, 20.0), (10.0, 24.0), (13.0, 20.0)];
+        let spatial: Spatial<f64, 10> = points.iter().collect();
+        let moments: MomentSet<f64, 10> = points.iter().collect();
+        let untouched = moments.clone();
+
+        // Accessing the normalized central moments first also initializes those they depend on
+        assert_eq!(
+            moments.normalized_central(),
+            &NormalizedCentral::from(&spatial)
+        );
+        assert_eq!(moments.central(), &Central::from(&spatial));
+        assert_eq!(moments.centroid(), spatial.centroid());
+        let centroid = moments.centroid().unwrap();
+        assert_abs_diff_eq!(centroid.x, 11.0, epsilon = 10e-12);
+        assert_abs_diff_eq!(centroid.y, 20.0 + 4.0 / 3.0, epsilon = 10e-12);
+
+        // Clones calculate the same moments, no matter whether they were taken before or after the caching
+        let cached = moments.clone();
+        assert_eq!(untouched.central(), moments.central());
+        assert_eq!(cached.normalized_central(), moments.normalized_central());
+        assert_eq!(moments.into_spatial(), spatial);
+    }
+
+    #[test]
+    fn test_empty() {
+        let points: [(f64, f64); 0] = [];
+        let moments: MomentSet<f64, 2> = points.iter().collect();
+        assert!(moments.centroid().is_none());
+        assert_eq!(moments.central(), &Central::from(moments.spatial()));
+        assert_eq!(
+            moments.normalized_central(),
+            &NormalizedCentral::from(moments.central())
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    implementation::{NormalizedCentralMoments, SealedSupportedOrder, Storage},
-    Central, Index, Moments, Order, Scalar, SupportedIndex, SupportedOrder,
+    implementation::{FixedStorage, NormalizedCentralMoments, SealedSupportedOrder, Storage},
+    Central, Index, Iter, Moments, Order, Scalar, Spatial, SupportedIndex, SupportedOrder,
 };
 
 /// The normalized central moments of an image or contour which are translational and scale invariant.
@@ -26,6 +26,15 @@ where
     }
 }
 
+impl<'a, T: Scalar, const ORDER: usize> From<&'a Spatial<T, ORDER>> for NormalizedCentral<T, ORDER>
+where
+    Order<ORDER>: SupportedOrder<T>,
+{
+    fn from(raw_moments: &'a Spatial<T, ORDER>) -> Self {
+        Self::from(&Central::from(raw_moments))
+    }
+}
+
 impl<T: Scalar, const ORDER: usize> Moments<T, ORDER> for NormalizedCentral<T, ORDER>
 where
     Order<ORDER>: SupportedOrder<T>,
@@ -39,12 +48,57 @@ where
     }
 }
 
+impl<T: Scalar, const ORDER: usize> NormalizedCentral<T, ORDER>
+where
+    Order<ORDER>: SupportedOrder<T>,
+{
+    /// Iterate over all moments as `(i, j, value)` triples in the order documented at [`Iter`].
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.0.as_slice(), ORDER)
+    }
+
+    /// Collect all moments into a vector, i.e. for using them as a feature vector.
+    /// The moments are ordered as documented at [`Iter`].
+    #[inline(always)]
+    pub fn to_vec(&self) -> Vec<T> {
+        self.0.as_slice().to_vec()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_abs_diff_eq;
 
     use crate::{central::Central, Moments, NormalizedCentral, Spatial};
 
+    #[test]
+    fn test_higher_orders() {
+        // The rectangle [1, 5] x [2, 4] is symmetric around its centroid (3, 3)
+        let points = [(1.0, 2.0), (5.0, 2.0), (5.0, 4.0), (1.0, 4.0)];
+        let normalized_central_moments = {
+            let moments: Spatial<f64, 5> = points.iter().collect();
+            NormalizedCentral::from(&Central::from(&moments))
+        };
+
+        assert_abs_diff_eq!(normalized_central_moments.get::<0, 0>(), 1.0);
+        assert_abs_diff_eq!(
+            normalized_central_moments.get::<4, 0>(),
+            128.0 / 5.0 / 8.0f64.powi(3),
+            epsilon = 10e-9
+        );
+        assert_abs_diff_eq!(
+            normalized_central_moments.get::<2, 2>(),
+            32.0 / 9.0 / 8.0f64.powi(3),
+            epsilon = 10e-9
+        );
+        assert_abs_diff_eq!(
+            normalized_central_moments.get::<3, 2>(),
+            0.0,
+            epsilon = 10e-9
+        );
+    }
+
     #[test]
     fn test_normalized_central_moments() {
         let points = [
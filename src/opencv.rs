@@ -0,0 +1,308 @@
+use crate::{
+    implementation::{FixedStorage, SealedSupportedOrder, Storage},
+    Central, Moments, NormalizedCentral, Order, Scalar, Spatial,
+};
+
+/// The moments up to the third order with the named fields of OpenCV's `cv::Moments`.
+/// This allows comparing the results 1:1 with those of OpenCV in C++ or Python.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct OpenCvMoments<T: Scalar> {
+    /// The spatial moment m00.
+    pub m00: T,
+    /// The spatial moment m10.
+    pub m10: T,
+    /// The spatial moment m01.
+    pub m01: T,
+    /// The spatial moment m20.
+    pub m20: T,
+    /// The spatial moment m11.
+    pub m11: T,
+    /// The spatial moment m02.
+    pub m02: T,
+    /// The spatial moment m30.
+    pub m30: T,
+    /// The spatial moment m21.
+    pub m21: T,
+    /// The spatial moment m12.
+    pub m12: T,
+    /// The spatial moment m03.
+    pub m03: T,
+    /// The central moment mu20.
+    pub mu20: T,
+    /// The central moment mu11.
+    pub mu11: T,
+    /// The central moment mu02.
+    pub mu02: T,
+    /// The central moment mu30.
+    pub mu30: T,
+    /// The central moment mu21.
+    pub mu21: T,
+    /// The central moment mu12.
+    pub mu12: T,
+    /// The central moment mu03.
+    pub mu03: T,
+    /// The normalized central moment nu20.
+    pub nu20: T,
+    /// The normalized central moment nu11.
+    pub nu11: T,
+    /// The normalized central moment nu02.
+    pub nu02: T,
+    /// The normalized central moment nu30.
+    pub nu30: T,
+    /// The normalized central moment nu21.
+    pub nu21: T,
+    /// The normalized central moment nu12.
+    pub nu12: T,
+    /// The normalized central moment nu03.
+    pub nu03: T,
+}
+
+impl<'a, T: Scalar> From<&'a Spatial<T, 3>> for OpenCvMoments<T> {
+    fn from(spatial: &'a Spatial<T, 3>) -> Self {
+        let central = Central::from(spatial);
+        let normalized_central = NormalizedCentral::from(&central);
+        OpenCvMoments {
+            m00: spatial.get::<0, 0>(),
+            m10: spatial.get::<1, 0>(),
+            m01: spatial.get::<0, 1>(),
+            m20: spatial.get::<2, 0>(),
+            m11: spatial.get::<1, 1>(),
+            m02: spatial.get::<0, 2>(),
+            m30: spatial.get::<3, 0>(),
+            m21: spatial.get::<2, 1>(),
+            m12: spatial.get::<1, 2>(),
+            m03: spatial.get::<0, 3>(),
+            mu20: central.get::<2, 0>(),
+            mu11: central.get::<1, 1>(),
+            mu02: central.get::<0, 2>(),
+            mu30: central.get::<3, 0>(),
+            mu21: central.get::<2, 1>(),
+            mu12: central.get::<1, 2>(),
+            mu03: central.get::<0, 3>(),
+            nu20: normalized_central.get::<2, 0>(),
+            nu11: normalized_central.get::<1, 1>(),
+            nu02: normalized_central.get::<0, 2>(),
+            nu30: normalized_central.get::<3, 0>(),
+            nu21: normalized_central.get::<2, 1>(),
+            nu12: normalized_central.get::<1, 2>(),
+            nu03: normalized_central.get::<0, 3>(),
+        }
+    }
+}
+
+impl<'a, T: Scalar> From<&'a OpenCvMoments<T>> for Spatial<T, 3> {
+    fn from(moments: &'a OpenCvMoments<T>) -> Self {
+        let mut storage = <Order<3> as SealedSupportedOrder<T>>::Storage::zeros();
+        *storage.get_mut::<0, 0>() = moments.m00;
+        *storage.get_mut::<1, 0>() = moments.m10;
+        *storage.get_mut::<0, 1>() = moments.m01;
+        *storage.get_mut::<2, 0>() = moments.m20;
+        *storage.get_mut::<1, 1>() = moments.m11;
+        *storage.get_mut::<0, 2>() = moments.m02;
+        *storage.get_mut::<3, 0>() = moments.m30;
+        *storage.get_mut::<2, 1>() = moments.m21;
+        *storage.get_mut::<1, 2>() = moments.m12;
+        *storage.get_mut::<0, 3>() = moments.m03;
+        Self(storage)
+    }
+}
+
+impl<'a, T: Scalar> From<&'a OpenCvMoments<T>> for Central<T, 3> {
+    fn from(moments: &'a OpenCvMoments<T>) -> Self {
+        // Like in OpenCV, mu00 equals m00 while mu10 and mu01 are zero by definition
+        let mut storage = <Order<3> as SealedSupportedOrder<T>>::Storage::zeros();
+        *storage.get_mut::<0, 0>() = moments.m00;
+        *storage.get_mut::<2, 0>() = moments.mu20;
+        *storage.get_mut::<1, 1>() = moments.mu11;
+        *storage.get_mut::<0, 2>() = moments.mu02;
+        *storage.get_mut::<3, 0>() = moments.mu30;
+        *storage.get_mut::<2, 1>() = moments.mu21;
+        *storage.get_mut::<1, 2>() = moments.mu12;
+        *storage.get_mut::<0, 3>() = moments.mu03;
+        Self(storage, Spatial::from(moments).centroid())
+    }
+}
+
+impl<'a, T: Scalar> From<&'a OpenCvMoments<T>> for NormalizedCentral<T, 3> {
+    fn from(moments: &'a OpenCvMoments<T>) -> Self {
+        // nu00 is one while nu10 and nu01 are zero by definition
+        let mut storage = <Order<3> as SealedSupportedOrder<T>>::Storage::zeros();
+        *storage.get_mut::<0, 0>() = T::ONE;
+        *storage.get_mut::<2, 0>() = moments.nu20;
+        *storage.get_mut::<1, 1>() = moments.nu11;
+        *storage.get_mut::<0, 2>() = moments.nu02;
+        *storage.get_mut::<3, 0>() = moments.nu30;
+        *storage.get_mut::<2, 1>() = moments.nu21;
+        *storage.get_mut::<1, 2>() = moments.nu12;
+        *storage.get_mut::<0, 3>() = moments.nu03;
+        Self(storage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Central, NormalizedCentral, OpenCvMoments, Spatial};
+
+    #[test]
+    fn test_opencv_moments() {
+        let points = [
+            (53, 19),
+            (52, 20),
+            (49, 20),
+            (48, 21),
+            (47, 21),
+            (46, 22),
+            (45, 22),
+            (44, 23),
+            (43, 23),
+            (42, 24),
+            (41, 24),
+            (39, 26),
+            (38, 26),
+            (34, 30),
+            (76, 30),
+            (77, 31),
+            (79, 31),
+            (80, 32),
+            (81, 32),
+            (83, 34),
+            (84, 34),
+            (86, 36),
+            (86, 37),
+            (87, 38),
+            (87, 39),
+            (88, 40),
+            (88, 47),
+            (86, 49),
+            (86, 50),
+            (83, 53),
+            (82, 53),
+            (81, 54),
+            (81, 55),
+            (82, 55),
+            (84, 57),
+            (84, 58),
+            (85, 59),
+            (85, 60),
+            (86, 61),
+            (86, 63),
+            (87, 64),
+            (87, 65),
+            (88, 66),
+            (93, 66),
+            (94, 65),
+            (94, 64),
+            (95, 63),
+            (95, 60),
+            (96, 59),
+            (99, 59),
+            (99, 53),
+            (98, 52),
+            (97, 52),
+            (96, 51),
+            (95, 51),
+            (94, 50),
+            (93, 50),
+            (90, 47),
+            (90, 46),
+            (91, 45),
+            (91, 44),
+            (92, 43),
+            (92, 42),
+            (93, 41),
+            (93, 39),
+            (94, 38),
+            (94, 36),
+            (91, 33),
+            (91, 32),
+            (85, 26),
+            (84, 26),
+            (82, 24),
+            (81, 24),
+            (80, 23),
+            (79, 23),
+            (78, 22),
+            (77, 22),
+            (76, 21),
+            (75, 21),
+            (74, 20),
+            (71, 20),
+            (70, 19),
+            (69, 19),
+            (67, 21),
+            (66, 21),
+            (62, 25),
+            (60, 25),
+            (54, 19),
+        ];
+
+        let spatial: Spatial<f64, 3> = points.iter().collect();
+        let moments = OpenCvMoments::from(&spatial);
+
+        // Compare with those values generated by OpenCV
+        assert_abs_diff_eq!(moments.m00, 703.0);
+        assert_abs_diff_eq!(moments.m10, 52175.166666666664);
+        assert_abs_diff_eq!(moments.m01, 25661.5);
+        assert_abs_diff_eq!(moments.m20, 4084450.6666666665);
+        assert_abs_diff_eq!(moments.m11, 2024477.75);
+        assert_abs_diff_eq!(moments.m02, 1071256.0);
+        assert_abs_diff_eq!(moments.m30, 332589780.65000004);
+        assert_abs_diff_eq!(moments.m21, 166738807.83333334);
+        assert_abs_diff_eq!(moments.m12, 89124447.63333333);
+        assert_abs_diff_eq!(moments.m03, 50269189.75);
+        assert_abs_diff_eq!(moments.mu20, 212120.628694484);
+        assert_abs_diff_eq!(moments.mu11, 119935.73091512569);
+        assert_abs_diff_eq!(moments.mu02, 134538.24431009952);
+        assert_abs_diff_eq!(moments.mu30, -2035756.4570507407);
+        assert_abs_diff_eq!(moments.mu21, -158011.91380318906, epsilon = 10e-7);
+        assert_abs_diff_eq!(moments.mu12, 862112.1277520265, epsilon = 10e-7);
+        assert_abs_diff_eq!(moments.mu03, 1343240.7361632437, epsilon = 10e-7);
+        assert_abs_diff_eq!(moments.nu20, 0.4292123953519341, epsilon = 10e-7);
+        assert_abs_diff_eq!(moments.nu11, 0.24268220715350328, epsilon = 10e-7);
+        assert_abs_diff_eq!(moments.nu02, 0.27222945011138916, epsilon = 10e-7);
+        assert_abs_diff_eq!(moments.nu30, -0.15535939037876878, epsilon = 10e-7);
+        assert_abs_diff_eq!(moments.nu21, -0.012058728594976597, epsilon = 10e-7);
+        assert_abs_diff_eq!(moments.nu12, 0.06579235651780115, epsilon = 10e-7);
+        assert_abs_diff_eq!(moments.nu03, 0.10250983666512753, epsilon = 10e-7);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        // A clockwise right triangle with legs of 3 and 4 has non-zero moments in every field
+        let points = [(0.0, 0.0), (0.0, 4.0), (3.0, 0.0)];
+        let spatial: Spatial<f64, 3> = points.iter().collect();
+        let central = Central::from(&spatial);
+        let normalized_central = NormalizedCentral::from(&central);
+
+        let moments = OpenCvMoments::from(&spatial);
+        assert_eq!(Spatial::from(&moments), spatial);
+        assert_eq!(Central::from(&moments), central);
+        assert_eq!(NormalizedCentral::from(&moments), normalized_central);
+
+        // The third order central moments are a⁴b / 270 and ab⁴ / 270
+        assert_abs_diff_eq!(moments.m00, 6.0, epsilon = 10e-12);
+        assert_abs_diff_eq!(moments.mu11, -2.0, epsilon = 10e-12);
+        assert_abs_diff_eq!(moments.mu30, 1.2, epsilon = 10e-12);
+        assert_abs_diff_eq!(moments.mu03, 128.0 / 45.0, epsilon = 10e-12);
+        assert_abs_diff_eq!(moments.nu20, 1.0 / 12.0, epsilon = 10e-12);
+        assert_abs_diff_eq!(moments.nu30, 1.2 / 6f64.powf(2.5), epsilon = 10e-12);
+    }
+
+    #[test]
+    fn test_degenerate_round_trip() {
+        // A contour without area keeps its missing centroid and its normalized moments through the round trip
+        let line = [(1.0, 1.0), (4.0, 4.0)];
+        let spatial: Spatial<f64, 3> = line.iter().collect();
+        let moments = OpenCvMoments::from(&spatial);
+        assert_eq!(moments.m00, 0.0);
+        assert_eq!(Spatial::from(&moments), spatial);
+        assert_eq!(Central::from(&moments), Central::from(&spatial));
+        assert!(Central::from(&moments).centroid().is_none());
+        assert_eq!(
+            NormalizedCentral::from(&moments),
+            NormalizedCentral::from(&spatial)
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{implementation::SealedSupportedOrder, Scalar};
 
-/// An order of image moments specified at compile time.
+/// An order of image moments specified at compile time. Orders up to 10 are supported.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct Order<const O: usize>;
 
@@ -12,3 +12,10 @@ impl<T: Scalar> SupportedOrder<T> for Order<0> {}
 impl<T: Scalar> SupportedOrder<T> for Order<1> {}
 impl<T: Scalar> SupportedOrder<T> for Order<2> {}
 impl<T: Scalar> SupportedOrder<T> for Order<3> {}
+impl<T: Scalar> SupportedOrder<T> for Order<4> {}
+impl<T: Scalar> SupportedOrder<T> for Order<5> {}
+impl<T: Scalar> SupportedOrder<T> for Order<6> {}
+impl<T: Scalar> SupportedOrder<T> for Order<7> {}
+impl<T: Scalar> SupportedOrder<T> for Order<8> {}
+impl<T: Scalar> SupportedOrder<T> for Order<9> {}
+impl<T: Scalar> SupportedOrder<T> for Order<10> {}
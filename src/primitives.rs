@@ -17,6 +17,9 @@ pub trait Scalar:
     /// The smallest incremental step of this floating-point number.
     const EPSILON: Self;
 
+    /// The largest finite value of this floating-point number.
+    const MAX: Self;
+
     /// The value of the scalar matching 0.0.
     const ZERO: Self;
 
@@ -47,6 +50,12 @@ pub trait Scalar:
     /// The value of the scalar matching 1/60.
     const F1_60: Self;
 
+    /// The value of the scalar matching π.
+    const PI: Self;
+
+    /// Converts an unsigned integer (i.e. a count or an index) into a scalar.
+    fn from_usize(value: usize) -> Self;
+
     /// Computes the absolute value of self
     fn abs(self) -> Self;
 
@@ -61,12 +70,31 @@ pub trait Scalar:
 
     /// Calculate the square root.
     fn sqrt(self) -> Self;
+
+    /// Calculate the base 10 logarithm.
+    fn log10(self) -> Self;
+
+    /// Calculate the natural logarithm.
+    fn ln(self) -> Self;
+
+    /// Calculate the exponential function e^self.
+    fn exp(self) -> Self;
+
+    /// Calculate the sine of an angle in radians.
+    fn sin(self) -> Self;
+
+    /// Calculate the cosine of an angle in radians.
+    fn cos(self) -> Self;
+
+    /// Calculate the four quadrant arctangent of self (y) and other (x) in radians.
+    fn atan2(self, other: Self) -> Self;
 }
 
 macro_rules! impl_scalar {
-    ( $scalar:ty ) => {
+    ( $scalar:ident ) => {
         impl Scalar for $scalar {
             const EPSILON: Self = <$scalar>::EPSILON;
+            const MAX: Self = <$scalar>::MAX;
             const ZERO: Self = 0.0;
             const ONE: Self = 1.0;
             const TWO: Self = 2.0;
@@ -77,6 +105,12 @@ macro_rules! impl_scalar {
             const F1_20: Self = 1.0 / 20.0;
             const F1_24: Self = 1.0 / 24.0;
             const F1_60: Self = 1.0 / 60.0;
+            const PI: Self = std::$scalar::consts::PI;
+
+            #[inline(always)]
+            fn from_usize(value: usize) -> Self {
+                value as $scalar
+            }
 
             #[inline(always)]
             fn abs(self) -> Self {
@@ -102,6 +136,36 @@ macro_rules! impl_scalar {
             fn sqrt(self) -> Self {
                 self.sqrt()
             }
+
+            #[inline(always)]
+            fn log10(self) -> Self {
+                self.log10()
+            }
+
+            #[inline(always)]
+            fn ln(self) -> Self {
+                self.ln()
+            }
+
+            #[inline(always)]
+            fn exp(self) -> Self {
+                self.exp()
+            }
+
+            #[inline(always)]
+            fn sin(self) -> Self {
+                self.sin()
+            }
+
+            #[inline(always)]
+            fn cos(self) -> Self {
+                self.cos()
+            }
+
+            #[inline(always)]
+            fn atan2(self, other: Self) -> Self {
+                self.atan2(other)
+            }
         }
     };
 }
@@ -109,6 +173,17 @@ macro_rules! impl_scalar {
 impl_scalar!(f32);
 impl_scalar!(f64);
 
+/// A generalization over different possible representations of grayscale or binary images.
+/// Implementing this trait on custom image types ensure their seamless usage with this crate.
+pub trait Image<S: Scalar> {
+    /// The number of columns of the image.
+    fn width(&self) -> usize;
+    /// The number of rows of the image.
+    fn height(&self) -> usize;
+    /// The intensity of the pixel in column x and row y, counted from the top left corner.
+    fn intensity(&self, x: usize, y: usize) -> S;
+}
+
 /// A generalization over different possible representations of points.
 /// Implementing this trait on custom structs ensure their seamless usage with this crate.
 pub trait Point<S: Scalar>: Clone {
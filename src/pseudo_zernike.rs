@@ -0,0 +1,172 @@
+use crate::{
+    implementation::{pseudo_zernike_radial, PolarStorage},
+    DiskMapping, Image, Scalar,
+};
+
+/// The pseudo-Zernike moments `P_nm` of an image mapped onto the unit disk.
+///
+/// The moments are defined for `0 <= n <= max_order` and `|m| <= min(n, max_repetition)`.
+/// Compared to [`Zernike`](crate::Zernike) moments, there is no parity constraint on `n - |m|`,
+/// which yields more moments of low order and makes them less sensitive to noise.
+/// Moments of negative repetition are the complex conjugates of the positive ones.
+/// The magnitudes `|P_nm|` are invariant under rotations of the image around the center of the disk.
+///
+/// Unlike for Zernike moments, there is no exact counterpart for polygon contours:
+/// The radial polynomials contain odd powers of the radius, which are no polynomials in `x` and `y`
+/// and thus cannot be expanded into geometric moments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PseudoZernike<T: Scalar>(pub(crate) PolarStorage<T>);
+
+impl<T: Scalar> PseudoZernike<T> {
+    /// Calculate the moments of a grayscale or binary image up to a specific order and repetition.
+    /// The pixels are sampled at their centers and those outside the unit disk are ignored.
+    pub fn from_image<I: Image<T>>(
+        image: &I,
+        max_order: usize,
+        max_repetition: usize,
+        mapping: DiskMapping,
+    ) -> Self {
+        let disk = mapping.unit_disk(image);
+        let max_repetition = max_repetition.min(max_order);
+
+        let mut moments = PolarStorage::zeros(max_order, max_repetition);
+        let mut buffer = vec![T::ZERO; (2 * max_order + 2) * (2 * max_order + 2)];
+        let mut radial = vec![T::ZERO; (max_order + 1) * (max_order + 1)];
+        disk.for_each_polar_pixel(image, max_repetition, |rho, angular| {
+            pseudo_zernike_radial(rho, max_order, &mut buffer, &mut radial);
+            for (m, angular) in angular.iter().enumerate() {
+                for n in m..=max_order {
+                    let r = radial[n * (max_order + 1) + m];
+                    let value = moments.at_mut(n, m);
+                    value.0 = r.mul_add(angular.0, value.0);
+                    value.1 = r.mul_add(angular.1, value.1);
+                }
+            }
+        });
+
+        let pixel_area = disk.pixel_area();
+        for n in 0..=max_order {
+            let scale = T::from_usize(n + 1) / T::PI * pixel_area;
+            for m in 0..=n.min(max_repetition) {
+                let value = moments.at_mut(n, m);
+                value.0 *= scale;
+                value.1 *= scale;
+            }
+        }
+
+        PseudoZernike(moments)
+    }
+
+    /// The maximal order n of the moments.
+    #[inline(always)]
+    pub fn max_order(&self) -> usize {
+        self.0.max_order()
+    }
+
+    /// The maximal absolute repetition |m| of the moments.
+    #[inline(always)]
+    pub fn max_repetition(&self) -> usize {
+        self.0.max_repetition()
+    }
+
+    /// Get the moment `P_nm` as real and imaginary part, if it is defined and was calculated.
+    pub fn get(&self, n: usize, m: isize) -> Option<(T, T)> {
+        match n >= m.unsigned_abs() {
+            true => self.0.get(n, m),
+            false => None,
+        }
+    }
+
+    /// Get the rotation invariant magnitude `|P_nm|`, if the moment is defined and was calculated.
+    pub fn magnitude(&self, n: usize, m: isize) -> Option<T> {
+        self.get(n, m)
+            .map(|(real, imaginary)| real.mul_add(real, imaginary * imaginary).sqrt())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use crate::{DiskMapping, GrayImage, PseudoZernike};
+
+    const SIZE: usize = 64;
+
+    fn image<F: Fn(f64, f64) -> f64>(f: F) -> Vec<f64> {
+        (0..SIZE * SIZE)
+            .map(|index| {
+                let x = (index % SIZE) as f64 + 0.5 - SIZE as f64 / 2.0;
+                let y = (index / SIZE) as f64 + 0.5 - SIZE as f64 / 2.0;
+                f(x / (SIZE as f64 / 2.0), y / (SIZE as f64 / 2.0))
+            })
+            .collect()
+    }
+
+    fn pseudo_zernike(pixels: &[f64], mapping: DiskMapping) -> PseudoZernike<f64> {
+        let image = GrayImage::new(SIZE, SIZE, pixels).unwrap();
+        PseudoZernike::from_image(&image, 6, 6, mapping)
+    }
+
+    #[test]
+    fn test_orthogonality() {
+        // An image equal to R_10 = 3 * rho - 2 only yields P_10 = 1
+        let moments = pseudo_zernike(
+            &image(|x, y| 3.0 * (x * x + y * y).sqrt() - 2.0),
+            DiskMapping::Inscribed,
+        );
+        assert_abs_diff_eq!(moments.get(1, 0).unwrap().0, 1.0, epsilon = 0.05);
+        for n in 0..=6 {
+            for m in 0..=n as isize {
+                if (n, m) != (1, 0) {
+                    assert_abs_diff_eq!(moments.magnitude(n, m).unwrap(), 0.0, epsilon = 0.05);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotation() {
+        // Rotating the pixels by 90 degrees keeps the magnitudes
+        let pixels = image(|x, y| (x + 0.3).max(0.0) * (1.0 - y).max(0.0));
+        let mut rotated = vec![0.0; SIZE * SIZE];
+        for y in 0..SIZE {
+            for x in 0..SIZE {
+                rotated[x * SIZE + (SIZE - 1 - y)] = pixels[y * SIZE + x];
+            }
+        }
+
+        for mapping in [DiskMapping::Inscribed, DiskMapping::Circumscribed] {
+            let expected = pseudo_zernike(&pixels, mapping);
+            let actual = pseudo_zernike(&rotated, mapping);
+            for n in 0..=6 {
+                for m in -(n as isize)..=n as isize {
+                    assert_abs_diff_eq!(
+                        expected.magnitude(n, m).unwrap(),
+                        actual.magnitude(n, m).unwrap(),
+                        epsilon = 10e-9
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_addressing() {
+        // The center pixel of an image of odd size lies exactly at the center of the disk
+        let image =
+            GrayImage::new(3, 3, &[0.0f32, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0][..]).unwrap();
+        let moments = PseudoZernike::<f32>::from_image(&image, 4, 2, DiskMapping::Inscribed);
+        assert_eq!(moments.max_order(), 4);
+        assert_eq!(moments.max_repetition(), 2);
+        assert!(moments.get(3, 2).is_some());
+        assert!(moments.get(3, -2).is_some());
+        assert!(moments.get(4, 3).is_none());
+        assert!(moments.get(1, 2).is_none());
+        assert!(moments.get(5, 0).is_none());
+
+        let scale = 4.0 / 9.0 / std::f32::consts::PI;
+        assert_abs_diff_eq!(moments.get(0, 0).unwrap().0, scale);
+        assert_abs_diff_eq!(moments.get(1, 0).unwrap().0, -2.0 * 2.0 * scale);
+        assert_abs_diff_eq!(moments.magnitude(2, 1).unwrap(), 0.0);
+    }
+}
@@ -0,0 +1,232 @@
+use std::convert::TryFrom;
+
+use crate::{
+    implementation::{principal_variances, standardized_moment, Storage},
+    Central, DynCentral, DynNormalizedCentral, Index, InsufficientOrder, NormalizedCentral, Order,
+    Scalar, SupportedIndex, SupportedOrder,
+};
+
+/// Common scalar descriptors of a shape derived from its central moments up to the fourth order.
+///
+/// With the eigenvalues `λ1 >= λ2` of the covariance matrix `[[μ20, μ11], [μ11, μ02]] / μ00`,
+/// i.e. the variances along the major and the minor principal axis, the descriptors are defined as follows.
+/// All of them except the orientation are invariant under translation, rotation and scale.
+/// For degenerate contours or images without any area, all descriptors are 0 like the moments themselves.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShapeDescriptors<T: Scalar> {
+    /// The eccentricity `sqrt(1 - λ2 / λ1)` of the [`Ellipse`](crate::Ellipse) of the same moments,
+    /// which is 0 for a circle and approaches 1 for a line.
+    pub eccentricity: T,
+    /// The elongation `1 - sqrt(λ2 / λ1)`, i.e. one minus the ratio of the minor and the major axis,
+    /// which is 0 for a circle and approaches 1 for a line.
+    pub elongation: T,
+    /// The area-normalized inertia `μ00² / (2π (μ20 + μ02))`,
+    /// which is 1 for a disk and smaller for any other shape as the disk has the least polar moment of inertia.
+    pub compactness: T,
+    /// The angle `0.5 * atan2(2μ11, μ20 - μ02)` of the major axis like [`Ellipse::orientation`](crate::Ellipse::orientation).
+    pub orientation: T,
+    /// The skewness `μ'30 / (μ00 λ1^(3/2))` and `μ'03 / (μ00 λ2^(3/2))` along the major and the minor axis,
+    /// where `μ'` are the central moments rotated by the orientation.
+    /// Their signs depend on the direction of the axes, which flips when the shape is rotated by 180 degrees.
+    pub skewness: (T, T),
+    /// The kurtosis `μ'40 / (μ00 λ1²)` and `μ'04 / (μ00 λ2²)` along the major and the minor axis,
+    /// which is 1.8 for uniform distributions like rectangles.
+    pub kurtosis: (T, T),
+}
+
+impl<T: Scalar> ShapeDescriptors<T> {
+    /// Calculate the descriptors from the central moments or the normalized central moments of an order >= 4.
+    /// The normalized central moments are the central moments of the shape scaled to unit mass.
+    /// As all descriptors are invariant under scale, the normalization does not change them.
+    fn calculate<S: Storage<T>>(moments: &S) -> Self {
+        let mut descriptors = ShapeDescriptors {
+            eccentricity: T::ZERO,
+            elongation: T::ZERO,
+            compactness: T::ZERO,
+            orientation: T::ZERO,
+            skewness: (T::ZERO, T::ZERO),
+            kurtosis: (T::ZERO, T::ZERO),
+        };
+        let m00 = moments.at(0, 0);
+        let (major, minor, orientation) =
+            match principal_variances(m00, moments.at(2, 0), moments.at(1, 1), moments.at(0, 2)) {
+                Some((major, minor, orientation)) if major > T::EPSILON => {
+                    (major, minor, orientation)
+                }
+                _ => return descriptors,
+            };
+
+        descriptors.eccentricity = (T::ONE - minor / major).sqrt();
+        descriptors.elongation = T::ONE - (minor / major).sqrt();
+        descriptors.compactness = m00 / (T::TWO * T::PI * (major + minor));
+        descriptors.orientation = orientation;
+
+        // Standardize the moments along the principal axes by the respective standard deviation
+        let rotation = (orientation.sin(), orientation.cos());
+        let (major, minor) = (major.sqrt(), minor.sqrt());
+        descriptors.skewness = (
+            standardized_moment(moments, 3, 0, rotation, major),
+            standardized_moment(moments, 0, 3, rotation, minor),
+        );
+        descriptors.kurtosis = (
+            standardized_moment(moments, 4, 0, rotation, major),
+            standardized_moment(moments, 0, 4, rotation, minor),
+        );
+        descriptors
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> From<&'a Central<T, ORDER>> for ShapeDescriptors<T>
+where
+    Order<ORDER>: SupportedOrder<T>,
+    Index<4, 0>: SupportedIndex<ORDER>,
+{
+    fn from(moments: &'a Central<T, ORDER>) -> Self {
+        Self::calculate(&moments.0)
+    }
+}
+
+impl<'a, T: Scalar, const ORDER: usize> From<&'a NormalizedCentral<T, ORDER>>
+    for ShapeDescriptors<T>
+where
+    Order<ORDER>: SupportedOrder<T>,
+    Index<4, 0>: SupportedIndex<ORDER>,
+{
+    fn from(moments: &'a NormalizedCentral<T, ORDER>) -> Self {
+        Self::calculate(&moments.0)
+    }
+}
+
+impl<'a, T: Scalar> TryFrom<&'a DynCentral<T>> for ShapeDescriptors<T> {
+    type Error = InsufficientOrder;
+
+    /// Calculate the descriptors, which requires moments of at least the fourth order.
+    fn try_from(moments: &'a DynCentral<T>) -> Result<Self, Self::Error> {
+        InsufficientOrder::check(4, moments.order())?;
+        Ok(Self::calculate(&moments.0))
+    }
+}
+
+impl<'a, T: Scalar> TryFrom<&'a DynNormalizedCentral<T>> for ShapeDescriptors<T> {
+    type Error = InsufficientOrder;
+
+    /// Calculate the descriptors, which requires moments of at least the fourth order.
+    fn try_from(moments: &'a DynNormalizedCentral<T>) -> Result<Self, Self::Error> {
+        InsufficientOrder::check(4, moments.order())?;
+        Ok(Self::calculate(&moments.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Central, DynCentral, DynSpatial, NormalizedCentral, ShapeDescriptors, Spatial};
+
+    fn descriptors(points: &[(f64, f64)]) -> ShapeDescriptors<f64> {
+        ShapeDescriptors::from(&Central::from(&points.iter().collect::<Spatial<f64, 4>>()))
+    }
+
+    fn transform(points: &[(f64, f64)], angle: f64, scale: f64) -> Vec<(f64, f64)> {
+        let (sin, cos) = angle.sin_cos();
+        points
+            .iter()
+            .map(|(x, y)| {
+                (
+                    scale * (x * cos - y * sin) + 7.0,
+                    scale * (x * sin + y * cos) - 3.0,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_disk() {
+        let points: Vec<_> = (0..2000)
+            .map(|index| {
+                let t = index as f64 / 2000.0 * 2.0 * std::f64::consts::PI;
+                (5.0 * t.cos(), 5.0 * t.sin())
+            })
+            .collect();
+        let actual = descriptors(&points);
+        assert_abs_diff_eq!(actual.eccentricity, 0.0, epsilon = 10e-3);
+        assert_abs_diff_eq!(actual.elongation, 0.0, epsilon = 10e-6);
+        assert_abs_diff_eq!(actual.compactness, 1.0, epsilon = 10e-6);
+        assert_abs_diff_eq!(actual.skewness.0, 0.0, epsilon = 10e-6);
+        assert_abs_diff_eq!(actual.kurtosis.0, 2.0, epsilon = 10e-4);
+        assert_abs_diff_eq!(actual.kurtosis.1, 2.0, epsilon = 10e-4);
+    }
+
+    #[test]
+    fn test_rectangle() {
+        // A 4x1 rectangle rotated by 0.3 radians
+        let points = [(-2.0, -0.5), (2.0, -0.5), (2.0, 0.5), (-2.0, 0.5)];
+        let actual = descriptors(&transform(&points, 0.3, 2.5));
+        assert_abs_diff_eq!(
+            actual.eccentricity,
+            (15.0f64 / 16.0).sqrt(),
+            epsilon = 10e-9
+        );
+        assert_abs_diff_eq!(actual.elongation, 0.75, epsilon = 10e-9);
+        assert_abs_diff_eq!(
+            actual.compactness,
+            16.0 / (2.0 * std::f64::consts::PI * 68.0 / 12.0),
+            epsilon = 10e-9
+        );
+        assert_abs_diff_eq!(actual.orientation, 0.3, epsilon = 10e-9);
+        assert_abs_diff_eq!(actual.skewness.0, 0.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(actual.skewness.1, 0.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(actual.kurtosis.0, 1.8, epsilon = 10e-9);
+        assert_abs_diff_eq!(actual.kurtosis.1, 1.8, epsilon = 10e-9);
+    }
+
+    #[test]
+    fn test_invariance() {
+        let points = [(0.0, 0.0), (6.0, 0.0), (1.0, 2.0), (0.0, 3.0)];
+        let expected = descriptors(&points);
+        assert!(expected.skewness.0.abs() > 0.1);
+
+        for (angle, scale) in [(0.4, 1.0), (-1.0, 3.0), (2.0, 0.5)] {
+            let actual = descriptors(&transform(&points, angle, scale));
+            assert_abs_diff_eq!(actual.eccentricity, expected.eccentricity, epsilon = 10e-9);
+            assert_abs_diff_eq!(actual.elongation, expected.elongation, epsilon = 10e-9);
+            assert_abs_diff_eq!(actual.compactness, expected.compactness, epsilon = 10e-9);
+            assert_abs_diff_eq!(
+                actual.skewness.0.abs(),
+                expected.skewness.0.abs(),
+                epsilon = 10e-9
+            );
+            assert_abs_diff_eq!(
+                actual.skewness.1.abs(),
+                expected.skewness.1.abs(),
+                epsilon = 10e-9
+            );
+            assert_abs_diff_eq!(actual.kurtosis.0, expected.kurtosis.0, epsilon = 10e-9);
+            assert_abs_diff_eq!(actual.kurtosis.1, expected.kurtosis.1, epsilon = 10e-9);
+        }
+
+        // The normalized central moments yield the same descriptors
+        let spatial: Spatial<f64, 4> = points.iter().collect();
+        let normalized = ShapeDescriptors::from(&NormalizedCentral::from(&spatial));
+        assert_abs_diff_eq!(
+            normalized.compactness,
+            expected.compactness,
+            epsilon = 10e-9
+        );
+        assert_abs_diff_eq!(normalized.kurtosis.1, expected.kurtosis.1, epsilon = 10e-9);
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let empty = descriptors(&[]);
+        assert_eq!(empty.compactness, 0.0);
+        assert_eq!(empty.skewness, (0.0, 0.0));
+        assert_eq!(descriptors(&[(1.0, 1.0), (3.0, 3.0)]), empty);
+
+        let points = [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)];
+        let moments = DynCentral::from(&DynSpatial::from_points(3, points.iter()));
+        assert!(ShapeDescriptors::try_from(&moments).is_err());
+    }
+}
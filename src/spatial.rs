@@ -1,8 +1,11 @@
 use std::iter::FromIterator;
 
 use crate::{
-    implementation::{Accumulator, SealedSupportedOrder, Storage},
-    Index, Moments, Order, Point, Scalar, SupportedIndex, SupportedOrder,
+    implementation::{
+        accumulate_image, calculate_centroid, Accumulator, FixedStorage, SealedSupportedOrder,
+        Storage,
+    },
+    Centroid, Image, Index, Iter, Moments, Order, Point, Scalar, SupportedIndex, SupportedOrder,
 };
 
 /// The raw, spatial moments of an image or contour.
@@ -26,6 +29,41 @@ where
     }
 }
 
+impl<T: Scalar, const ORDER: usize> Spatial<T, ORDER>
+where
+    Order<ORDER>: SupportedOrder<T>,
+{
+    /// Calculate the moments of a grayscale or binary image.
+    /// Like OpenCV, each pixel is located at its integer coordinates `(x, y)` weighted by its intensity.
+    pub fn from_image<I: Image<T>>(image: &I) -> Self {
+        let mut storage = <Order<ORDER> as SealedSupportedOrder<T>>::Storage::zeros();
+        accumulate_image(image, &mut storage);
+        Self(storage)
+    }
+
+    /// The centroid `(m10 / m00, m01 / m00)`, which is `None` for degenerate contours or images without any area.
+    #[inline(always)]
+    pub fn centroid(&self) -> Option<Centroid<T>>
+    where
+        Index<1, 0>: SupportedIndex<ORDER>,
+    {
+        calculate_centroid(&self.0)
+    }
+
+    /// Iterate over all moments as `(i, j, value)` triples in the order documented at [`Iter`].
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.0.as_slice(), ORDER)
+    }
+
+    /// Collect all moments into a vector, i.e. for using them as a feature vector.
+    /// The moments are ordered as documented at [`Iter`].
+    #[inline(always)]
+    pub fn to_vec(&self) -> Vec<T> {
+        self.0.as_slice().to_vec()
+    }
+}
+
 impl<T: Scalar, P: Point<T>, const ORDER: usize> FromIterator<P> for Spatial<T, ORDER>
 where
     Order<ORDER>: SupportedOrder<T>,
@@ -56,7 +94,7 @@ where
 mod tests {
     use approx::assert_abs_diff_eq;
 
-    use crate::{Moments, Spatial};
+    use crate::{DynSpatial, GrayImage, Moments, Spatial};
 
     #[test]
     fn test_empty() {
@@ -72,6 +110,86 @@ mod tests {
         let _: Spatial<f64, 3> = points.into_iter().collect();
     }
 
+    #[test]
+    fn test_higher_orders() {
+        // The moments of the rectangle [0, 2] x [0, 3] are 2^(i + 1) * 3^(j + 1) / ((i + 1) * (j + 1))
+        let points = [(0.0, 0.0), (2.0, 0.0), (2.0, 3.0), (0.0, 3.0)];
+        let moments: Spatial<f64, 6> = points.iter().collect();
+        assert_abs_diff_eq!(moments.get::<0, 0>(), 6.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(moments.get::<4, 0>(), 32.0 * 3.0 / 5.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(moments.get::<2, 2>(), 8.0 * 27.0 / 9.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(moments.get::<1, 4>(), 4.0 * 243.0 / 10.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(moments.get::<3, 3>(), 16.0 * 81.0 / 16.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(moments.get::<0, 6>(), 2.0 * 2187.0 / 7.0, epsilon = 10e-9);
+
+        // The orientation of the contour must not matter
+        let reversed: Spatial<f64, 6> = points.iter().rev().collect();
+        assert_abs_diff_eq!(
+            reversed.get::<3, 3>(),
+            moments.get::<3, 3>(),
+            epsilon = 10e-9
+        );
+    }
+
+    #[test]
+    fn test_centroid() {
+        let points = [(1.0, 2.0), (5.0, 2.0), (5.0, 4.0), (1.0, 4.0)];
+        let moments: Spatial<f64, 1> = points.iter().collect();
+        let centroid = moments.centroid().unwrap();
+        assert_abs_diff_eq!(centroid.x, 3.0, epsilon = 10e-9);
+        assert_abs_diff_eq!(centroid.y, 3.0, epsilon = 10e-9);
+
+        // Neither contours without any area nor empty images have a centroid
+        let line: Spatial<f64, 2> = [(1.0, 1.0), (4.0, 4.0)].iter().collect();
+        assert!(line.centroid().is_none());
+        let image = GrayImage::new(3, 2, &[0.0f32; 6][..]).unwrap();
+        assert!(Spatial::<f32, 3>::from_image(&image).centroid().is_none());
+        assert!(DynSpatial::<f32>::from_image(0, &image)
+            .centroid()
+            .is_none());
+    }
+
+    #[test]
+    fn test_image() {
+        // A filled 4x3 rectangle with its top left pixel at (2, 1)
+        let mut pixels = [false; 8 * 5];
+        for y in 1..4 {
+            for x in 2..6 {
+                pixels[y * 8 + x] = true;
+            }
+        }
+        let image = GrayImage::new(8, 5, &pixels[..]).unwrap();
+        let moments = Spatial::<f64, 3>::from_image(&image);
+        assert_abs_diff_eq!(moments.get::<0, 0>(), 12.0);
+        assert_abs_diff_eq!(moments.get::<1, 0>(), 3.0 * 14.0);
+        assert_abs_diff_eq!(moments.get::<0, 1>(), 4.0 * 6.0);
+        assert_abs_diff_eq!(moments.get::<2, 1>(), 54.0 * 6.0);
+        assert_abs_diff_eq!(moments.get::<0, 3>(), 4.0 * 36.0);
+
+        let dynamic = DynSpatial::<f64>::from_image(3, &image);
+        assert_eq!(dynamic.to_vec(), moments.to_vec());
+    }
+
+    #[test]
+    fn test_iter() {
+        // The clockwise rectangle [1, 2] x [1, 4] has distinct moments up to order 2
+        let points = [(1.0, 1.0), (1.0, 4.0), (2.0, 4.0), (2.0, 1.0)];
+        let moments: Spatial<f64, 2> = points.iter().collect();
+
+        let indices: Vec<_> = moments.iter().map(|(i, j, _)| (i, j)).collect();
+        assert_eq!(
+            indices,
+            vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (0, 2)]
+        );
+
+        let values = moments.to_vec();
+        let expected = [3.0, 4.5, 7.0, 7.5, 11.25, 21.0];
+        assert_eq!(values.len(), expected.len());
+        for (value, expected) in values.iter().zip(expected.iter()) {
+            assert_abs_diff_eq!(value, expected, epsilon = 10e-12);
+        }
+    }
+
     #[test]
     fn test_spatial_moments() {
         let points = [
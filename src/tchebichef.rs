@@ -0,0 +1,166 @@
+use crate::{
+    implementation::{separable_inverse, separable_transform, tchebichef_polynomials},
+    Image, Scalar,
+};
+
+/// The discrete Tchebichef moments `T_pq` of an image for `p <= order_x` and `q <= order_y`.
+///
+/// The moments are `T_pq = Σ_x Σ_y t_p(x) t_q(y) f(x, y)` with the orthonormal Tchebichef polynomials `t_n`,
+/// which are defined directly on the pixel grid and thus free of any discretization error.
+/// Consequently, [`Tchebichef::reconstruct`] restores the image exactly if the orders are `width - 1` and `height - 1`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tchebichef<T: Scalar> {
+    width: usize,
+    height: usize,
+    order_x: usize,
+    order_y: usize,
+    moments: Vec<T>,
+}
+
+impl<T: Scalar> Tchebichef<T> {
+    /// Calculate the moments of a grayscale or binary image up to specific orders in x and y.
+    /// The orders are limited to `width - 1` and `height - 1`, beyond which the polynomials are not defined.
+    pub fn from_image<I: Image<T>>(image: &I, order_x: usize, order_y: usize) -> Self {
+        let (width, height) = (image.width(), image.height());
+        let order_x = order_x.min(width.saturating_sub(1));
+        let order_y = order_y.min(height.saturating_sub(1));
+        let columns = tchebichef_polynomials::<T>(width, order_x);
+        let rows = tchebichef_polynomials::<T>(height, order_y);
+
+        let moments = separable_transform(image, &columns, &rows, order_x, order_y);
+
+        Tchebichef {
+            width,
+            height,
+            order_x,
+            order_y,
+            moments,
+        }
+    }
+
+    /// The width of the image the moments were calculated from.
+    #[inline(always)]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The height of the image the moments were calculated from.
+    #[inline(always)]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The order up to which the moments were calculated in x.
+    #[inline(always)]
+    pub fn order_x(&self) -> usize {
+        self.order_x
+    }
+
+    /// The order up to which the moments were calculated in y.
+    #[inline(always)]
+    pub fn order_y(&self) -> usize {
+        self.order_y
+    }
+
+    /// Get the moment at a specific position, if it is covered by the orders.
+    #[inline(always)]
+    pub fn get(&self, p: usize, q: usize) -> Option<T> {
+        match p <= self.order_x && q <= self.order_y {
+            true => Some(self.moments[q * (self.order_x + 1) + p]),
+            false => None,
+        }
+    }
+
+    /// Restore the row-major pixels of the image from the moments by the inverse transform.
+    pub fn reconstruct(&self) -> Vec<T> {
+        let (width, height) = (self.width, self.height);
+        let columns = tchebichef_polynomials::<T>(width, self.order_x);
+        let rows = tchebichef_polynomials::<T>(height, self.order_y);
+
+        separable_inverse(
+            &self.moments,
+            &columns,
+            &rows,
+            (width, height),
+            self.order_x,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use crate::{GrayImage, Tchebichef};
+
+    // A small binary mask of the letter "F"
+    const MASK: [bool; 35] = [
+        true, true, true, true, true, //
+        true, false, false, false, false, //
+        true, false, false, false, false, //
+        true, true, true, true, false, //
+        true, false, false, false, false, //
+        true, false, false, false, false, //
+        true, false, false, false, false, //
+    ];
+
+    #[test]
+    fn test_exact_reconstruction() {
+        let image = GrayImage::new(5, 7, &MASK[..]).unwrap();
+        let moments = Tchebichef::<f64>::from_image(&image, 10, 10);
+        assert_eq!((moments.order_x(), moments.order_y()), (4, 6));
+        assert_eq!((moments.width(), moments.height()), (5, 7));
+
+        for (actual, expected) in moments.reconstruct().iter().zip(MASK) {
+            assert_abs_diff_eq!(*actual, if expected { 1.0 } else { 0.0 }, epsilon = 10e-12);
+        }
+    }
+
+    #[test]
+    fn test_exact_reconstruction_large() {
+        let (width, height) = (96, 64);
+        let pixels: Vec<f32> = (0..width * height)
+            .map(|index| ((index * 7919) % 256) as f32 / 255.0)
+            .collect();
+        let image = GrayImage::new(width, height, &pixels).unwrap();
+        let moments = Tchebichef::<f64>::from_image(&image, width - 1, height - 1);
+        for (actual, expected) in moments.reconstruct().iter().zip(&pixels) {
+            assert_abs_diff_eq!(*actual, *expected as f64, epsilon = 10e-9);
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        for (width, height) in [(0, 3), (3, 0)] {
+            let image = GrayImage::<f32>::new(width, height, &[]).unwrap();
+            let moments = Tchebichef::<f64>::from_image(&image, 2, 2);
+            assert_eq!((moments.width(), moments.height()), (width, height));
+            assert_eq!(moments.get(0, 0), Some(0.0));
+            assert!(moments.reconstruct().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_moments() {
+        // T_00 is the sum of the intensities divided by the square root of the pixel count
+        let image = GrayImage::new(5, 7, &MASK[..]).unwrap();
+        let moments = Tchebichef::<f64>::from_image(&image, 2, 1);
+        assert_abs_diff_eq!(
+            moments.get(0, 0).unwrap(),
+            14.0 / 35f64.sqrt(),
+            epsilon = 10e-12
+        );
+        assert!(moments.get(2, 1).is_some());
+        assert!(moments.get(3, 0).is_none());
+        assert!(moments.get(0, 2).is_none());
+
+        // Truncated moments only approximate the image
+        let error: f64 = moments
+            .reconstruct()
+            .iter()
+            .zip(MASK)
+            .map(|(actual, expected)| (actual - if expected { 1.0 } else { 0.0 }).abs())
+            .sum();
+        assert!(error > 1.0);
+    }
+}
@@ -0,0 +1,309 @@
+use crate::{
+    implementation::{
+        bounding_box, calculate_complex_moment, zernike_radial, zernike_radial_coefficients,
+        PolarStorage, UnitDisk,
+    },
+    DiskMapping, DynSpatial, Image, Point, Scalar,
+};
+
+/// The Zernike moments `Z_nm` of an image mapped onto the unit disk.
+///
+/// The moments are defined for `0 <= n <= max_order` and `|m| <= min(n, max_repetition)` with even `n - |m|`.
+/// Moments of negative repetition are the complex conjugates of the positive ones.
+/// The magnitudes `|Z_nm|` are invariant under rotations of the image around the center of the disk.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Zernike<T: Scalar>(pub(crate) PolarStorage<T>);
+
+impl<T: Scalar> Zernike<T> {
+    /// Calculate the moments of a grayscale or binary image up to a specific order and repetition.
+    /// The pixels are sampled at their centers and those outside the unit disk are ignored.
+    pub fn from_image<I: Image<T>>(
+        image: &I,
+        max_order: usize,
+        max_repetition: usize,
+        mapping: DiskMapping,
+    ) -> Self {
+        let disk = mapping.unit_disk(image);
+        let max_repetition = max_repetition.min(max_order);
+
+        let mut moments = PolarStorage::zeros(max_order, max_repetition);
+        let mut radial = vec![T::ZERO; (max_order + 1) * (max_order + 1)];
+        disk.for_each_polar_pixel(image, max_repetition, |rho, angular| {
+            zernike_radial(rho, max_order, &mut radial);
+            for (m, angular) in angular.iter().enumerate() {
+                for n in (m..=max_order).step_by(2) {
+                    let r = radial[n * (max_order + 1) + m];
+                    let value = moments.at_mut(n, m);
+                    value.0 = r.mul_add(angular.0, value.0);
+                    value.1 = r.mul_add(angular.1, value.1);
+                }
+            }
+        });
+
+        let pixel_area = disk.pixel_area();
+        for n in 0..=max_order {
+            let scale = T::from_usize(n + 1) / T::PI * pixel_area;
+            for m in (n % 2..=n.min(max_repetition)).step_by(2) {
+                let value = moments.at_mut(n, m);
+                value.0 *= scale;
+                value.1 *= scale;
+            }
+        }
+
+        Zernike(moments)
+    }
+
+    /// Calculate the exact moments of the polygon given by the points of a contour up to a specific order and repetition.
+    /// The polygon is mapped into the disk circumscribed about its bounding box.
+    pub fn from_points<P: Point<T>, I: IntoIterator<Item = P>>(
+        max_order: usize,
+        max_repetition: usize,
+        points: I,
+    ) -> Self {
+        let points: Vec<P> = points.into_iter().collect();
+        let (min, max) = match bounding_box(&points) {
+            Some(bounds) => bounds,
+            None => {
+                return Zernike(PolarStorage::zeros(
+                    max_order,
+                    max_repetition.min(max_order),
+                ))
+            }
+        };
+
+        let (width, height) = (max.0 - min.0, max.1 - min.1);
+        let center = (min.0 + width * T::F1_2, min.1 + height * T::F1_2);
+        let radius = width.mul_add(width, height * height).sqrt() * T::F1_2;
+        let radius = match radius > T::EPSILON {
+            true => radius,
+            false => T::ONE,
+        };
+        Self::from_points_in_disk(max_order, max_repetition, center, radius, points)
+    }
+
+    /// Calculate the exact moments of the polygon given by the points of a contour up to a specific order and repetition.
+    /// The polygon is mapped into the disk of the given center and radius; parts outside the disk are not clipped.
+    ///
+    /// The moments are expanded from the geometric moments of the mapped polygon, which loses precision to cancellation for high orders (roughly beyond 20).
+    pub fn from_points_in_disk<P: Point<T>, I: IntoIterator<Item = P>>(
+        max_order: usize,
+        max_repetition: usize,
+        center: (T, T),
+        radius: T,
+        points: I,
+    ) -> Self {
+        let disk = UnitDisk { center, radius };
+        let spatial = DynSpatial::from_points(
+            max_order,
+            points.into_iter().map(|point| disk.map_point(point)),
+        );
+
+        // rho^(m + 2t) * exp(-i * m * theta) = (x + iy)^t * (x - iy)^(t + m) integrates to the complex moment c_t(t+m)
+        let max_repetition = max_repetition.min(max_order);
+        let mut moments = PolarStorage::zeros(max_order, max_repetition);
+        for n in 0..=max_order {
+            let scale = T::from_usize(n + 1) / T::PI;
+            for m in (n % 2..=n.min(max_repetition)).step_by(2) {
+                let value = moments.at_mut(n, m);
+                for (s, coefficient) in zernike_radial_coefficients::<T>(n, m)
+                    .into_iter()
+                    .enumerate()
+                {
+                    let t = (n - m) / 2 - s;
+                    let (real, imaginary) = calculate_complex_moment(&spatial.0, t, t + m);
+                    value.0 = coefficient.mul_add(real, value.0);
+                    value.1 = coefficient.mul_add(imaginary, value.1);
+                }
+                value.0 *= scale;
+                value.1 *= scale;
+            }
+        }
+
+        Zernike(moments)
+    }
+
+    /// The maximal order n of the moments.
+    #[inline(always)]
+    pub fn max_order(&self) -> usize {
+        self.0.max_order()
+    }
+
+    /// The maximal absolute repetition |m| of the moments.
+    #[inline(always)]
+    pub fn max_repetition(&self) -> usize {
+        self.0.max_repetition()
+    }
+
+    /// Get the moment `Z_nm` as real and imaginary part, if it is defined and was calculated.
+    pub fn get(&self, n: usize, m: isize) -> Option<(T, T)> {
+        match n >= m.unsigned_abs() && (n - m.unsigned_abs()) % 2 == 0 {
+            true => self.0.get(n, m),
+            false => None,
+        }
+    }
+
+    /// Get the rotation invariant magnitude `|Z_nm|`, if the moment is defined and was calculated.
+    pub fn magnitude(&self, n: usize, m: isize) -> Option<T> {
+        self.get(n, m)
+            .map(|(real, imaginary)| real.mul_add(real, imaginary * imaginary).sqrt())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use crate::{DiskMapping, GrayImage, Zernike};
+
+    const SIZE: usize = 64;
+
+    fn image<F: Fn(f64, f64) -> f64>(f: F) -> Vec<f64> {
+        (0..SIZE * SIZE)
+            .map(|index| {
+                let x = (index % SIZE) as f64 + 0.5 - SIZE as f64 / 2.0;
+                let y = (index / SIZE) as f64 + 0.5 - SIZE as f64 / 2.0;
+                f(x / (SIZE as f64 / 2.0), y / (SIZE as f64 / 2.0))
+            })
+            .collect()
+    }
+
+    fn zernike(pixels: &[f64], mapping: DiskMapping) -> Zernike<f64> {
+        let image = GrayImage::new(SIZE, SIZE, pixels).unwrap();
+        Zernike::from_image(&image, 8, 8, mapping)
+    }
+
+    #[test]
+    fn test_disk() {
+        // For a filled unit disk, Z_00 is the normalized area and all other moments vanish
+        let moments = zernike(&image(|_, _| 1.0), DiskMapping::Inscribed);
+        let (real, imaginary) = moments.get(0, 0).unwrap();
+        assert_abs_diff_eq!(real, 1.0, epsilon = 10e-3);
+        assert_abs_diff_eq!(imaginary, 0.0);
+        for n in 1..=8 {
+            assert_abs_diff_eq!(
+                moments.magnitude(n, (n % 2) as isize).unwrap(),
+                0.0,
+                epsilon = 0.05
+            );
+        }
+    }
+
+    #[test]
+    fn test_orthogonality() {
+        // An image equal to R_20 only yields Z_20 = 1
+        let moments = zernike(
+            &image(|x, y| 2.0 * (x * x + y * y) - 1.0),
+            DiskMapping::Inscribed,
+        );
+        assert_abs_diff_eq!(moments.get(2, 0).unwrap().0, 1.0, epsilon = 0.05);
+        assert_abs_diff_eq!(moments.magnitude(0, 0).unwrap(), 0.0, epsilon = 0.05);
+        assert_abs_diff_eq!(moments.magnitude(4, 0).unwrap(), 0.0, epsilon = 0.05);
+        assert_abs_diff_eq!(moments.magnitude(2, 2).unwrap(), 0.0, epsilon = 0.05);
+    }
+
+    #[test]
+    fn test_rotation() {
+        // Rotating the pixels by 90 degrees keeps the magnitudes and maps Z_nm to Z_nm * i^-m
+        let pixels = image(|x, y| (x + 0.3).max(0.0) * (1.0 - y).max(0.0));
+        let mut rotated = vec![0.0; SIZE * SIZE];
+        for y in 0..SIZE {
+            for x in 0..SIZE {
+                rotated[x * SIZE + (SIZE - 1 - y)] = pixels[y * SIZE + x];
+            }
+        }
+
+        for mapping in [DiskMapping::Inscribed, DiskMapping::Circumscribed] {
+            let expected = zernike(&pixels, mapping);
+            let actual = zernike(&rotated, mapping);
+            for n in 0..=8 {
+                for m in (-(n as isize)..=n as isize).step_by(2) {
+                    assert_abs_diff_eq!(
+                        expected.magnitude(n, m).unwrap(),
+                        actual.magnitude(n, m).unwrap(),
+                        epsilon = 10e-9
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_polygon_disk() {
+        // A regular polygon approximating the unit disk
+        let points = (0..3600).map(|i| {
+            let (sin, cos) = (i as f64 * std::f64::consts::PI / 1800.0).sin_cos();
+            (cos, sin)
+        });
+        let moments = Zernike::from_points_in_disk(10, 10, (0.0, 0.0), 1.0, points);
+        assert_abs_diff_eq!(moments.get(0, 0).unwrap().0, 1.0, epsilon = 10e-6);
+        for n in 1..=10 {
+            for m in (-(n as isize)..=n as isize).step_by(2) {
+                assert_abs_diff_eq!(moments.magnitude(n, m).unwrap(), 0.0, epsilon = 10e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_polygon_image() {
+        // The exact moments of a rectangle approximate those sampled from pixels
+        let pixels = image(|x, y| match x.abs() < 0.5 && y.abs() < 0.25 {
+            true => 1.0,
+            false => 0.0,
+        });
+        let expected = zernike(&pixels, DiskMapping::Inscribed);
+        let actual = Zernike::from_points_in_disk(
+            8,
+            8,
+            (32.0, 32.0),
+            32.0,
+            [(16.0, 24.0), (48.0, 24.0), (48.0, 40.0), (16.0, 40.0)],
+        );
+        for n in 0..=8 {
+            for m in (-(n as isize)..=n as isize).step_by(2) {
+                let (expected, actual) = (expected.get(n, m).unwrap(), actual.get(n, m).unwrap());
+                assert_abs_diff_eq!(expected.0, actual.0, epsilon = 10e-3);
+                assert_abs_diff_eq!(expected.1, actual.1, epsilon = 10e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_polygon_rotation() {
+        // The bounding box of the points is symmetric, such that rotating by 90 degrees keeps the disk
+        let points = [(-2.0, -1.0), (3.0, -3.0), (1.0, 2.0), (-3.0, 3.0)];
+        let expected = Zernike::from_points(8, 6, points);
+        let actual = Zernike::from_points(8, 6, points.iter().map(|(x, y)| (-y, *x)));
+        assert_eq!(actual.max_repetition(), 6);
+        for n in 0..=8 {
+            for m in (-(n as isize)..=n as isize)
+                .step_by(2)
+                .filter(|m| m.abs() <= 6)
+            {
+                assert_abs_diff_eq!(
+                    expected.magnitude(n, m).unwrap(),
+                    actual.magnitude(n, m).unwrap(),
+                    epsilon = 10e-12
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_addressing() {
+        let image = GrayImage::new(2, 2, &[1.0f32, 0.0, 0.0, 1.0][..]).unwrap();
+        let moments = Zernike::<f32>::from_image(&image, 4, 2, DiskMapping::Circumscribed);
+        assert_eq!(moments.max_order(), 4);
+        assert_eq!(moments.max_repetition(), 2);
+        assert!(moments.get(4, 2).is_some());
+        assert_eq!(
+            moments
+                .get(3, -1)
+                .map(|(real, imaginary)| (real, -imaginary)),
+            moments.get(3, 1)
+        );
+        assert!(moments.get(4, 4).is_none());
+        assert!(moments.get(5, 1).is_none());
+        assert!(moments.get(3, 2).is_none());
+        assert!(moments.get(1, 3).is_none());
+    }
+}